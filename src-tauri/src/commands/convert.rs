@@ -1,13 +1,80 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
-use pdfium_render::prelude::*;
 use tauri::Emitter;
-use tauri::Manager;
-use crate::models::{ConvertProgress, SlideInfo};
+use crate::models::{
+    ConvertOptions, ConvertProgress, Hotspot, Project, SlideInfo, TextLayer, TextWord,
+};
+use super::format::{detect_format, SourceFormat};
+use super::image_sequence::is_image_sequence;
+use super::pptx::{self, PptxDeck};
+use super::project::validate_path_within;
+use super::rasterize::{
+    available_rasterizers, DocumentInfo, LinkTarget, OutlineEntry, PageLink, PageSelection,
+    RenderOptions, RenderedPage,
+};
+use super::settings::read_settings;
+
+const DEFAULT_RENDER_DPI: f32 = 200.0;
+
+#[cfg(windows)]
+const SOFFICE_NAMES: &[&str] = &["soffice.exe"];
+#[cfg(not(windows))]
+const SOFFICE_NAMES: &[&str] = &["soffice", "libreoffice"];
+
+#[cfg(windows)]
+const LIBREOFFICE_SETUP_HINT: &str = "1. アプリと同じフォルダに LibreOfficePortable を配置\n\
+     2. LibreOffice をインストール\n\
+     3. 環境変数 LIBREOFFICE_PATH に soffice.exe のパスを設定";
+#[cfg(target_os = "macos")]
+const LIBREOFFICE_SETUP_HINT: &str = "1. LibreOffice を /Applications にインストール\n\
+     2. 環境変数 LIBREOFFICE_PATH に soffice のパスを設定";
+#[cfg(all(unix, not(target_os = "macos")))]
+const LIBREOFFICE_SETUP_HINT: &str = "1. LibreOffice をインストール (例: sudo apt install libreoffice-impress)\n\
+     2. soffice / libreoffice コマンドを PATH に追加\n\
+     3. 環境変数 LIBREOFFICE_PATH に soffice のパスを設定";
+
+/// Platform-specific directories where LibreOffice installs `soffice`.
+fn libreoffice_install_dirs() -> Vec<PathBuf> {
+    #[cfg(windows)]
+    let dirs = vec![
+        PathBuf::from(r"C:\Program Files\LibreOffice\program"),
+        PathBuf::from(r"C:\Program Files (x86)\LibreOffice\program"),
+    ];
+
+    #[cfg(target_os = "macos")]
+    let dirs = vec![PathBuf::from("/Applications/LibreOffice.app/Contents/MacOS")];
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    let dirs = {
+        let mut dirs = vec![
+            PathBuf::from("/usr/lib/libreoffice/program"),
+            PathBuf::from("/usr/lib64/libreoffice/program"),
+            PathBuf::from("/usr/local/lib/libreoffice/program"),
+        ];
+        // Tarball installs from libreoffice.org land in /opt/libreofficeX.Y
+        if let Ok(entries) = std::fs::read_dir("/opt") {
+            let mut opt_dirs: Vec<PathBuf> = entries
+                .filter_map(|e| e.ok())
+                .filter(|e| e.file_name().to_string_lossy().starts_with("libreoffice"))
+                .map(|e| e.path().join("program"))
+                .collect();
+            opt_dirs.sort();
+            opt_dirs.reverse();
+            dirs.extend(opt_dirs);
+        }
+        dirs.push(PathBuf::from("/snap/bin"));
+        dirs
+    };
+
+    dirs
+}
 
-const RENDER_DPI: f32 = 200.0;
+fn find_libreoffice() -> Result<PathBuf, String> {
+    let mut searched_paths: Vec<String> = Vec::new();
 
-fn find_libreoffice() -> Option<PathBuf> {
     // 1. Next to the executable (for portable distribution)
+    #[cfg(windows)]
     if let Ok(exe) = std::env::current_exe() {
         if let Some(exe_dir) = exe.parent() {
             let portable = exe_dir
@@ -16,190 +83,434 @@ fn find_libreoffice() -> Option<PathBuf> {
                 .join("libreoffice")
                 .join("program")
                 .join("soffice.exe");
+            searched_paths.push(format!("portable: {}", portable.display()));
             if portable.exists() {
-                return Some(portable);
+                return Ok(portable);
             }
         }
     }
 
     // 2. Standard install paths
-    for dir in &[
-        r"C:\Program Files\LibreOffice\program",
-        r"C:\Program Files (x86)\LibreOffice\program",
-    ] {
-        let path = PathBuf::from(dir).join("soffice.exe");
-        if path.exists() {
-            return Some(path);
+    for dir in libreoffice_install_dirs() {
+        for name in SOFFICE_NAMES {
+            let path = dir.join(name);
+            searched_paths.push(format!("install: {}", path.display()));
+            if path.exists() {
+                return Ok(path);
+            }
+        }
+    }
+
+    // 3. PATH
+    if let Some(path_var) = std::env::var_os("PATH") {
+        for dir in std::env::split_paths(&path_var) {
+            for name in SOFFICE_NAMES {
+                let path = dir.join(name);
+                if path.is_file() {
+                    return Ok(path);
+                }
+            }
         }
+        searched_paths.push(format!("PATH: {}", SOFFICE_NAMES.join(", ")));
     }
 
-    // 3. Environment variable
+    // 4. Environment variable
     if let Ok(custom_path) = std::env::var("LIBREOFFICE_PATH") {
         let path = PathBuf::from(&custom_path);
+        searched_paths.push(format!("LIBREOFFICE_PATH: {}", path.display()));
         if path.exists() {
-            return Some(path);
+            return Ok(path);
         }
     }
 
-    None
+    Err(format!(
+        "PPTX / ODP / Keynote などのプレゼンテーションを変換するには LibreOffice が必要です。\n\
+         以下のいずれかの方法で設定してください：\n\
+         {}\n\n\
+         または、PDFに変換してからインポートしてください。\n\n\
+         検索パス:\n{}",
+        LIBREOFFICE_SETUP_HINT,
+        searched_paths.join("\n")
+    ))
 }
 
+/// How long LibreOffice may take when the settings do not say otherwise.
+const DEFAULT_LIBREOFFICE_TIMEOUT_SECS: u64 = 180;
+
+/// Convert a presentation to PDF inside `work_dir`, returning the PDF's path.
+///
+/// Each run gets its own LibreOffice profile so it neither waits on the lock of
+/// a running instance nor inherits its state, and writes into an empty output
+/// folder so a PDF left over from an earlier run can never be picked up.
 fn convert_pptx_to_pdf(
     soffice_path: &std::path::Path,
     pptx_path: &str,
-    output_dir: &str,
+    work_dir: &std::path::Path,
 ) -> Result<String, String> {
-    let output = std::process::Command::new(soffice_path)
-        .args(&[
+    let profile_dir = work_dir.join("profile");
+    let output_dir = work_dir.join("out");
+    std::fs::create_dir_all(&output_dir)
+        .map_err(|e| format!("一時ディレクトリの作成に失敗: {}", e))?;
+
+    let timeout = std::time::Duration::from_secs(
+        read_settings()
+            .libreoffice_timeout_secs
+            .filter(|secs| *secs > 0)
+            .unwrap_or(DEFAULT_LIBREOFFICE_TIMEOUT_SECS),
+    );
+
+    let mut command = std::process::Command::new(soffice_path);
+    // Own process group, so the launcher and the soffice.bin it starts can be killed together
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(&mut command, 0);
+
+    let mut child = command
+        .arg(format!("-env:UserInstallation={}", file_url(&profile_dir)))
+        .args([
             "--headless",
-            "--convert-to", "pdf",
-            "--outdir", output_dir,
-            pptx_path,
+            "--norestore",
+            // Keep hidden slides so page N of the PDF is still slide N of the deck
+            "--convert-to",
+            r#"pdf:impress_pdf_Export:{"ExportHiddenSlides":{"type":"boolean","value":"true"}}"#,
+            "--outdir",
         ])
-        .output()
+        .arg(&output_dir)
+        .arg(pptx_path)
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
         .map_err(|e| format!("LibreOffice の起動に失敗: {}", e))?;
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("PPTX→PDF 変換に失敗: {}", stderr));
+    // Drain both pipes on their own threads so a chatty process cannot block on a full pipe
+    let stdout = read_pipe(child.stdout.take());
+    let stderr = read_pipe(child.stderr.take());
+
+    let started = std::time::Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break Some(status),
+            Ok(None) if started.elapsed() >= timeout => {
+                kill_process_tree(&mut child);
+                break None;
+            }
+            Ok(None) => std::thread::sleep(std::time::Duration::from_millis(100)),
+            Err(e) => return Err(format!("LibreOffice の終了待機に失敗: {}", e)),
+        }
+    };
+    // Bounded wait: a surviving grandchild could keep the pipes open indefinitely
+    let output = [stdout, stderr]
+        .into_iter()
+        .filter_map(|rx| rx.recv_timeout(std::time::Duration::from_secs(2)).ok())
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let Some(status) = status else {
+        return Err(format!(
+            "LibreOffice が {} 秒以内に終了しなかったため中断しました。\n{}",
+            timeout.as_secs(),
+            output
+        ));
+    };
+    if !status.success() {
+        return Err(format!("PDF への変換に失敗 ({}):\n{}", status, output));
     }
 
-    // Derive the PDF filename from the PPTX filename
-    let pptx_name = std::path::Path::new(pptx_path)
-        .file_stem()
-        .ok_or("ファイル名の取得に失敗")?
-        .to_str()
-        .ok_or("ファイル名のUTF-8変換に失敗")?;
-    let pdf_path = std::path::Path::new(output_dir).join(format!("{}.pdf", pptx_name));
+    // LibreOffice may exit successfully without writing anything, e.g. for unreadable input
+    let pdf_path = std::fs::read_dir(&output_dir)
+        .ok()
+        .and_then(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+                .find(|path| {
+                    path.extension()
+                        .is_some_and(|ext| ext.eq_ignore_ascii_case("pdf"))
+                })
+        })
+        .ok_or_else(|| format!("変換されたPDFファイルが見つかりません\n{}", output))?;
 
-    if !pdf_path.exists() {
-        return Err("変換されたPDFファイルが見つかりません".to_string());
-    }
+    Ok(pdf_path.to_string_lossy().to_string())
+}
 
-    Ok(pdf_path.to_str().unwrap().to_string())
+fn read_pipe<R: std::io::Read + Send + 'static>(
+    pipe: Option<R>,
+) -> std::sync::mpsc::Receiver<String> {
+    let (tx, rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let mut bytes = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut bytes);
+        }
+        let _ = tx.send(String::from_utf8_lossy(&bytes).trim().to_string());
+    });
+    rx
 }
 
-fn convert_internal(
-    app: &tauri::AppHandle,
-    source_path: &str,
-    output_dir: &str,
-    start_index: usize,
-) -> Result<Vec<SlideInfo>, String> {
-    let output_path = PathBuf::from(output_dir);
-    std::fs::create_dir_all(&output_path)
-        .map_err(|e| format!("出力ディレクトリの作成に失敗: {}", e))?;
+/// Kill a process together with the processes it started.
+fn kill_process_tree(child: &mut std::process::Child) {
+    #[cfg(unix)]
+    let _ = std::process::Command::new("kill")
+        .args(["-KILL", &format!("-{}", child.id())])
+        .status();
+    #[cfg(windows)]
+    let _ = std::process::Command::new("taskkill")
+        .args(["/PID", &child.id().to_string(), "/T", "/F"])
+        .status();
+    let _ = child.kill();
+    let _ = child.wait();
+}
 
-    // Handle PPTX/PPT: convert to PDF first via LibreOffice
-    let lower = source_path.to_lowercase();
-    let is_pptx = lower.ends_with(".pptx") || lower.ends_with(".ppt");
-
-    let effective_pdf_path = if is_pptx {
-        let soffice = find_libreoffice().ok_or_else(|| {
-            "PPTX ファイルを変換するには LibreOffice が必要です。\n\
-             以下のいずれかの方法で設定してください：\n\
-             1. アプリと同じフォルダに LibreOfficePortable を配置\n\
-             2. LibreOffice をインストール\n\
-             3. 環境変数 LIBREOFFICE_PATH に soffice.exe のパスを設定\n\n\
-             または、PDFに変換してからインポートしてください。"
-                .to_string()
-        })?;
-
-        app.emit(
-            "convert-progress",
-            ConvertProgress {
-                current: 0,
-                total: 0,
-                message: "PPTX を PDF に変換中 (LibreOffice)...".to_string(),
-            },
-        )
-        .ok();
+/// `file://` URL for a local path, as LibreOffice expects for `-env:` options.
+fn file_url(path: &std::path::Path) -> String {
+    let path = path.to_string_lossy().replace('\\', "/");
+    let mut url = String::from("file://");
+    if !path.starts_with('/') {
+        // Windows drive paths: file:///C:/...
+        url.push('/');
+    }
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'/' | b':' | b'-' | b'_' | b'.' | b'~' => {
+                url.push(byte as char)
+            }
+            _ => url.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    url
+}
 
-        convert_pptx_to_pdf(&soffice, source_path, output_dir)?
-    } else {
-        source_path.to_string()
-    };
+/// A source file ready for rasterization. Presentations are converted to a
+/// PDF in a temporary directory first, which is removed again by `cleanup`.
+struct PreparedSource {
+    pdf_path: String,
+    format: SourceFormat,
+    work_dir: Option<PathBuf>,
+}
 
-    let pdfium_dll_path = resolve_pdfium_path(app)?;
+impl PreparedSource {
+    fn cleanup(&self) {
+        if let Some(work_dir) = &self.work_dir {
+            let _ = std::fs::remove_dir_all(work_dir);
+        }
+    }
+}
 
-    let pdfium = Pdfium::new(
-        Pdfium::bind_to_library(pdfium_dll_path.to_str().unwrap_or_default())
-            .map_err(|e| format!("PDFium DLL の読み込みに失敗: {}", e))?,
-    );
+fn prepare_source(app: &tauri::AppHandle, source_path: &str) -> Result<PreparedSource, String> {
+    let format = detect_format(std::path::Path::new(source_path))?;
 
-    let document = pdfium
-        .load_pdf_from_file(&effective_pdf_path, None)
-        .map_err(|e| format!("PDF ファイルを開けません: {}", e))?;
+    if !format.needs_libreoffice() {
+        return Ok(PreparedSource {
+            pdf_path: source_path.to_string(),
+            format,
+            work_dir: None,
+        });
+    }
 
-    let total_pages = document.pages().len() as usize;
+    let soffice = find_libreoffice()?;
 
     app.emit(
         "convert-progress",
         ConvertProgress {
             current: 0,
-            total: total_pages,
-            message: "スライド画像に変換中...".to_string(),
+            total: 0,
+            message: "プレゼンテーションを PDF に変換中 (LibreOffice)...".to_string(),
         },
     )
     .ok();
 
-    let render_config = PdfRenderConfig::new()
-        .set_target_width(
-            (RENDER_DPI / 72.0
-                * document
-                    .pages()
-                    .first()
-                    .map(|p| p.width().value)
-                    .unwrap_or(792.0)) as i32,
-        )
-        .set_maximum_height(
-            (RENDER_DPI / 72.0
-                * document
-                    .pages()
-                    .first()
-                    .map(|p| p.height().value)
-                    .unwrap_or(612.0)) as i32,
-        );
+    let work_dir = std::env::temp_dir().join(format!("slide-linker-{}", uuid::Uuid::new_v4()));
+    match convert_pptx_to_pdf(&soffice, source_path, &work_dir) {
+        Ok(pdf_path) => Ok(PreparedSource {
+            pdf_path,
+            format,
+            work_dir: Some(work_dir),
+        }),
+        Err(e) => {
+            let _ = std::fs::remove_dir_all(&work_dir);
+            Err(e)
+        }
+    }
+}
 
-    let mut slides: Vec<SlideInfo> = Vec::with_capacity(total_pages);
+fn render_options(render_dpi: Option<f32>, render_width: Option<u32>) -> RenderOptions {
+    RenderOptions {
+        dpi: render_dpi.filter(|dpi| *dpi > 0.0).unwrap_or(DEFAULT_RENDER_DPI),
+        target_width: render_width.filter(|width| *width > 0),
+        pages: None,
+    }
+}
 
-    for (index, page) in document.pages().iter().enumerate() {
-        let global_index = start_index + index;
-        let file_name = format!("slide-{:03}.png", global_index + 1);
-        let file_path = output_path.join(&file_name);
+/// Rasterize with the backends allowed by the user's settings, falling back to
+/// the next one when a backend fails before producing any page.
+fn rasterize_with_fallback(
+    app: &tauri::AppHandle,
+    pdf_path: &str,
+    options: &RenderOptions,
+    on_start: &mut dyn FnMut(DocumentInfo),
+    on_page: &mut dyn FnMut(RenderedPage) -> Result<(), String>,
+) -> Result<(), String> {
+    let preference = read_settings().rasterizer;
+    let mut errors: Vec<String> = Vec::new();
+
+    for rasterizer in available_rasterizers(app, preference)? {
+        let mut pages_done = 0;
+        let result = rasterizer.rasterize(pdf_path, options, on_start, &mut |page| {
+            pages_done += 1;
+            on_page(page)
+        });
 
-        let bitmap = page
-            .render_with_config(&render_config)
-            .map_err(|e| format!("ページ {} のレンダリングに失敗: {}", index + 1, e))?;
+        match result {
+            Ok(()) => return Ok(()),
+            Err(e) if pages_done == 0 => {
+                errors.push(format!("[{}] {}", rasterizer.name(), e));
+            }
+            Err(e) => return Err(e),
+        }
+    }
 
-        let img = bitmap.as_image();
+    Err(errors.join("\n\n"))
+}
 
-        img.into_rgba8()
-            .save(&file_path)
-            .map_err(|e| format!("画像の保存に失敗 ({}): {}", file_name, e))?;
+fn convert_internal(
+    app: &tauri::AppHandle,
+    source_path: &str,
+    output_dir: &str,
+    start_index: usize,
+    options: &ConvertOptions,
+) -> Result<Vec<SlideInfo>, String> {
+    let output_path = PathBuf::from(output_dir);
+    std::fs::create_dir_all(&output_path)
+        .map_err(|e| format!("出力ディレクトリの作成に失敗: {}", e))?;
 
-        let relative_path = format!("slides/{}", file_name);
-        slides.push(SlideInfo {
-            id: format!("slide-{:03}", global_index + 1),
-            index: global_index,
-            label: format!("Slide {}", global_index + 1),
-            image_path: relative_path,
-        });
+    let source = prepare_source(app, source_path)?;
+
+    // Shape-level click actions rarely survive LibreOffice, so read them from the package
+    let pptx_deck: Option<PptxDeck> = if source.format == SourceFormat::OoxmlPresentation {
+        pptx::read_pptx(std::path::Path::new(source_path)).ok()
+    } else {
+        None
+    };
 
-        app.emit(
-            "convert-progress",
-            ConvertProgress {
-                current: index + 1,
-                total: total_pages,
-                message: format!(
-                    "変換中... ({}/{})",
-                    index + 1,
-                    total_pages
+    let mut render_options = render_options(options.render_dpi, options.render_width);
+    render_options.pages = options
+        .pages
+        .as_deref()
+        .filter(|spec| !spec.trim().is_empty())
+        .map(PageSelection::parse)
+        .transpose()?;
+
+    let mut slides: Vec<SlideInfo> = Vec::new();
+    // Shared between the start and page callbacks
+    let selected_pages: RefCell<Vec<usize>> = RefCell::new(Vec::new());
+    let mut outline: Vec<OutlineEntry> = Vec::new();
+    let mut rendered = 0;
+
+    let result = rasterize_with_fallback(
+        app,
+        &source.pdf_path,
+        &render_options,
+        &mut |document| {
+            let total = document.pages.len();
+            selected_pages.replace(document.pages);
+            outline = document.outline;
+            app.emit(
+                "convert-progress",
+                ConvertProgress {
+                    current: 0,
+                    total,
+                    message: "スライド画像に変換中...".to_string(),
+                },
+            )
+            .ok();
+        },
+        &mut |page| {
+            let selected_pages = selected_pages.borrow();
+            let total_pages = selected_pages.len();
+            // Slide indices keep the original page number so provenance stays accurate
+            let index = page.index;
+            let global_index = start_index + index;
+            let file_name = format!("slide-{:03}.png", global_index + 1);
+            let file_path = output_path.join(&file_name);
+
+            let pptx_slide = pptx_deck.as_ref().and_then(|deck| deck.slides.get(index));
+            let label = pptx_slide
+                .and_then(|slide| slide.title.as_deref())
+                .map(truncate_label)
+                .or_else(|| page.text.as_ref().and_then(label_from_text))
+                .unwrap_or_else(|| format!("Slide {}", global_index + 1));
+
+            let pdf_hotspots = to_hotspots(&page.links, start_index, &selected_pages);
+            let hotspots = match pptx_slide {
+                Some(pptx_slide) => merge_hotspots(
+                    to_hotspots(&pptx_slide.links, start_index, &selected_pages),
+                    pdf_hotspots,
                 ),
-            },
-        )
-        .ok();
+                None => pdf_hotspots,
+            };
+
+            page.image
+                .save(&file_path)
+                .map_err(|e| format!("画像の保存に失敗 ({}): {}", file_name, e))?;
+
+            let (width, height) = page.image.dimensions();
+            let relative_path = format!("slides/{}", file_name);
+            slides.push(SlideInfo {
+                id: slide_id(global_index),
+                index: global_index,
+                label,
+                image_path: relative_path,
+                hotspots,
+                source_page: index,
+                width,
+                height,
+                aspect_ratio: ratio_from_dimensions(width, height),
+                text_layer: page.text,
+                notes: pptx_slide.and_then(|slide| slide.notes.clone()),
+                is_main: true,
+                enabled: !pptx_slide.is_some_and(|slide| slide.hidden),
+                section: pptx_slide.and_then(|slide| slide.section.clone()),
+                graph_links: Vec::new(),
+            });
+
+            rendered += 1;
+            app.emit(
+                "convert-progress",
+                ConvertProgress {
+                    current: rendered,
+                    total: total_pages,
+                    message: format!(
+                        "変換中... ({}/{})",
+                        rendered,
+                        total_pages
+                    ),
+                },
+            )
+            .ok();
+            Ok(())
+        },
+    );
+
+    // Clean up the temporary PDF if the source was converted by LibreOffice
+    source.cleanup();
+    result?;
+
+    // Each section opens with a main slide; the rest of the section are its sub-slides
+    let mut previous_section: Option<String> = None;
+    for slide in &mut slides {
+        if let Some(section) = &slide.section {
+            slide.is_main = previous_section.as_ref() != Some(section);
+            previous_section = Some(section.clone());
+        }
     }
 
+    // PDFs exported by LibreOffice carry one bookmark per slide, which says nothing about hierarchy
+    if !source.format.needs_libreoffice() {
+        apply_outline(&mut slides, &outline);
+    }
+
+    let total_pages = selected_pages.borrow().len();
     app.emit(
         "convert-progress",
         ConvertProgress {
@@ -210,12 +521,171 @@ fn convert_internal(
     )
     .ok();
 
-    // Clean up temporary PDF if we converted from PPTX
-    if is_pptx {
-        let _ = std::fs::remove_file(&effective_pdf_path);
+    Ok(slides)
+}
+
+/// Use the PDF's bookmark tree as the slide hierarchy. Top-level bookmark pages are
+/// proposed as main slides, pages bookmarked below them become sub-slides linked from
+/// their parent's page, and bookmark titles replace the detected labels.
+fn apply_outline(slides: &mut [SlideInfo], outline: &[OutlineEntry]) {
+    let positions: HashMap<usize, usize> = slides
+        .iter()
+        .enumerate()
+        .map(|(pos, slide)| (slide.source_page, pos))
+        .collect();
+    let top_level: HashSet<usize> = outline.iter().map(|entry| entry.page).collect();
+    let mut labelled: HashSet<usize> = HashSet::new();
+
+    fn walk(
+        slides: &mut [SlideInfo],
+        positions: &HashMap<usize, usize>,
+        top_level: &HashSet<usize>,
+        labelled: &mut HashSet<usize>,
+        entries: &[OutlineEntry],
+        parent_page: Option<usize>,
+    ) {
+        for entry in entries {
+            if let Some(&pos) = positions.get(&entry.page) {
+                // The first bookmark on a page names it; parents come before their children
+                if !entry.title.is_empty() && labelled.insert(entry.page) {
+                    slides[pos].label = truncate_label(&entry.title);
+                }
+                match parent_page {
+                    None => slides[pos].is_main = true,
+                    Some(parent) if parent != entry.page => {
+                        if let Some(&parent_pos) = positions.get(&parent) {
+                            if !top_level.contains(&entry.page) {
+                                slides[pos].is_main = false;
+                            }
+                            let child_id = slides[pos].id.clone();
+                            let links = &mut slides[parent_pos].graph_links;
+                            if !links.contains(&child_id) {
+                                links.push(child_id);
+                            }
+                        }
+                    }
+                    Some(_) => {}
+                }
+            }
+            walk(slides, positions, top_level, labelled, &entry.children, Some(entry.page));
+        }
+    }
+
+    walk(slides, &positions, &top_level, &mut labelled, outline, None);
+}
+
+/// Labels longer than this are cut with an ellipsis.
+const MAX_LABEL_CHARS: usize = 60;
+/// Words at least this fraction of the largest font size count as part of the title run.
+const TITLE_FONT_RATIO: f64 = 0.9;
+
+/// Guess a slide title from its text: the topmost line set in the page's largest font.
+fn label_from_text(layer: &TextLayer) -> Option<String> {
+    // Big page numbers and decorative digits should not pick the line on their own
+    let has_letters = |word: &TextWord| word.text.chars().any(char::is_alphabetic);
+    let max_size = layer
+        .words
+        .iter()
+        .filter(|word| has_letters(word))
+        .map(|word| word.font_size)
+        .fold(0.0, f64::max);
+    if max_size <= 0.0 {
+        return None;
     }
 
-    Ok(slides)
+    let mut title_words: Vec<&TextWord> = layer
+        .words
+        .iter()
+        .filter(|word| word.font_size >= max_size * TITLE_FONT_RATIO)
+        .collect();
+    let top = title_words
+        .iter()
+        .filter(|word| has_letters(word))
+        .min_by(|a, b| a.y.total_cmp(&b.y))?;
+    let (line_top, line_bottom) = (top.y, top.y + top.h);
+    title_words.retain(|word| {
+        let center = word.y + word.h / 2.0;
+        center >= line_top && center <= line_bottom
+    });
+    title_words.sort_by(|a, b| a.x.total_cmp(&b.x));
+
+    let title = title_words
+        .iter()
+        .map(|word| word.text.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!title.is_empty()).then(|| truncate_label(&title))
+}
+
+pub fn truncate_label(label: &str) -> String {
+    if label.chars().count() <= MAX_LABEL_CHARS {
+        return label.to_string();
+    }
+    let truncated: String = label.chars().take(MAX_LABEL_CHARS - 1).collect();
+    format!("{}…", truncated.trim_end())
+}
+
+pub fn slide_id(global_index: usize) -> String {
+    format!("slide-{:03}", global_index + 1)
+}
+
+/// Build hotspots for a page's links, dropping slide links to pages that were not imported.
+fn to_hotspots(links: &[PageLink], start_index: usize, imported_pages: &[usize]) -> Vec<Hotspot> {
+    links
+        .iter()
+        .filter(|link| match link.target {
+            LinkTarget::Page(page_index) => imported_pages.binary_search(&page_index).is_ok(),
+            LinkTarget::Url(_) => true,
+        })
+        .map(|link| new_link_hotspot(link.bounds, link.target.clone(), start_index))
+        .collect()
+}
+
+fn new_link_hotspot(
+    (x, y, w, h): (f64, f64, f64, f64),
+    target: LinkTarget,
+    start_index: usize,
+) -> Hotspot {
+    let (link_type, target_id, url) = match target {
+        LinkTarget::Url(url) => ("url", None, Some(url)),
+        LinkTarget::Page(page_index) => ("slide", Some(slide_id(start_index + page_index)), None),
+    };
+    Hotspot {
+        id: format!("hs-{}", &uuid::Uuid::new_v4().simple().to_string()[..8]),
+        name: None,
+        x,
+        y,
+        w,
+        h,
+        link_type: link_type.to_string(),
+        target_id,
+        url,
+        tooltip: None,
+        style: None,
+    }
+}
+
+/// Combine hotspots from two sources, dropping secondary hotspots that cover
+/// mostly the same area as one of the primary ones.
+fn merge_hotspots(primary: Vec<Hotspot>, secondary: Vec<Hotspot>) -> Vec<Hotspot> {
+    let mut merged = primary;
+    for candidate in secondary {
+        let duplicate = merged.iter().any(|existing| {
+            let overlap_w = (existing.x + existing.w).min(candidate.x + candidate.w)
+                - existing.x.max(candidate.x);
+            let overlap_h = (existing.y + existing.h).min(candidate.y + candidate.h)
+                - existing.y.max(candidate.y);
+            if overlap_w <= 0.0 || overlap_h <= 0.0 {
+                return false;
+            }
+            let smaller = (existing.w * existing.h).min(candidate.w * candidate.h);
+            smaller > 0.0 && overlap_w * overlap_h / smaller > 0.5
+        });
+        if !duplicate {
+            merged.push(candidate);
+        }
+    }
+    merged
 }
 
 #[tauri::command]
@@ -223,8 +693,9 @@ pub async fn convert_pdf_to_images(
     app: tauri::AppHandle,
     pdf_path: String,
     output_dir: String,
+    options: Option<ConvertOptions>,
 ) -> Result<Vec<SlideInfo>, String> {
-    convert_internal(&app, &pdf_path, &output_dir, 0)
+    convert_internal(&app, &pdf_path, &output_dir, 0, &options.unwrap_or_default())
 }
 
 #[tauri::command]
@@ -233,8 +704,128 @@ pub async fn convert_to_images_with_offset(
     source_path: String,
     output_dir: String,
     start_index: usize,
+    options: Option<ConvertOptions>,
 ) -> Result<Vec<SlideInfo>, String> {
-    convert_internal(&app, &source_path, &output_dir, start_index)
+    convert_internal(&app, &source_path, &output_dir, start_index, &options.unwrap_or_default())
+}
+
+/// Re-render every slide from its source file at the project's current render
+/// resolution, overwriting the images in place. Ids, `image_path`s and hotspots
+/// are untouched since hotspots are stored as percentages.
+/// Returns the project with the new pixel sizes recorded on each slide.
+#[tauri::command]
+pub async fn rerender_project(
+    app: tauri::AppHandle,
+    project_dir: String,
+    mut project: Project,
+) -> Result<Project, String> {
+    let project_base = PathBuf::from(&project_dir);
+
+    // Group slides by source file, keyed to their zero-based page in that file
+    let mut sources: Vec<(String, Vec<(usize, usize)>)> = Vec::new();
+    for (slide_pos, slide) in project.slides.iter().enumerate() {
+        let source = slide
+            .source_file
+            .clone()
+            .unwrap_or_else(|| project.source_file.clone());
+        let page = slide.source_page.unwrap_or_else(|| {
+            // Older projects: pages of one file were imported with consecutive indices
+            let first_index = project
+                .slides
+                .iter()
+                .filter(|s| s.source_file.as_deref().unwrap_or(&project.source_file) == source)
+                .map(|s| s.index)
+                .min()
+                .unwrap_or(0);
+            slide.index - first_index
+        });
+        match sources.iter_mut().find(|(path, _)| *path == source) {
+            Some((_, pages)) => pages.push((page, slide_pos)),
+            None => sources.push((source, vec![(page, slide_pos)])),
+        }
+    }
+
+    if let Some((missing, _)) = sources
+        .iter()
+        .find(|(path, _)| !std::path::Path::new(path).exists())
+    {
+        return Err(format!("元ファイルが見つかりません: {}", missing));
+    }
+
+    let total = project.slides.len();
+    let mut rendered = 0usize;
+
+    app.emit(
+        "convert-progress",
+        ConvertProgress {
+            current: 0,
+            total,
+            message: "スライド画像を再レンダリング中...".to_string(),
+        },
+    )
+    .ok();
+
+    for (source_path, pages) in &sources {
+        // Imported images have no higher-resolution original to render from
+        if is_image_sequence(std::path::Path::new(source_path)) {
+            rendered += pages.len();
+            continue;
+        }
+
+        let source = prepare_source(&app, source_path)?;
+        let page_indices: Vec<usize> = pages.iter().map(|(page, _)| *page).collect();
+        let render_options = RenderOptions {
+            pages: Some(PageSelection::from_indices(&page_indices)),
+            ..render_options(project.render_dpi, project.render_width)
+        };
+
+        let result = rasterize_with_fallback(
+            &app,
+            &source.pdf_path,
+            &render_options,
+            &mut |_| {},
+            &mut |page| {
+                let (width, height) = page.image.dimensions();
+                for &(_, slide_pos) in pages.iter().filter(|(p, _)| *p == page.index) {
+                    let slide = &mut project.slides[slide_pos];
+                    let dest = validate_path_within(&project_base, &slide.image_path)?;
+                    page.image
+                        .save(&dest)
+                        .map_err(|e| format!("画像の保存に失敗 ({}): {}", slide.image_path, e))?;
+                    slide.width = Some(width);
+                    slide.height = Some(height);
+                    slide.aspect_ratio = Some(ratio_from_dimensions(width, height));
+                    rendered += 1;
+
+                    app.emit(
+                        "convert-progress",
+                        ConvertProgress {
+                            current: rendered,
+                            total,
+                            message: format!("再レンダリング中... ({}/{})", rendered, total),
+                        },
+                    )
+                    .ok();
+                }
+                Ok(())
+            },
+        );
+
+        source.cleanup();
+        result?;
+    }
+
+    app.emit(
+        "convert-progress",
+        ConvertProgress {
+            current: total,
+            total,
+            message: "再レンダリング完了".to_string(),
+        },
+    )
+    .ok();
+
+    Ok(project)
 }
 
 #[tauri::command]
@@ -242,7 +833,7 @@ pub async fn detect_aspect_ratio_from_slides(
     slides_dir: String,
 ) -> Result<String, String> {
     let dir = PathBuf::from(&slides_dir);
-    let entries: Vec<_> = std::fs::read_dir(&dir)
+    let mut entries: Vec<_> = std::fs::read_dir(&dir)
         .map_err(|e| format!("ディレクトリの読み取りに失敗: {}", e))?
         .filter_map(|e| e.ok())
         .filter(|e| {
@@ -251,10 +842,13 @@ pub async fn detect_aspect_ratio_from_slides(
                 .map(|ext| ext == "png")
                 .unwrap_or(false)
         })
+        .map(|e| e.path())
         .collect();
+    // read_dir order is unspecified; use the first slide
+    entries.sort();
 
-    if let Some(entry) = entries.first() {
-        let dimensions = image::image_dimensions(entry.path())
+    if let Some(path) = entries.first() {
+        let dimensions = image::image_dimensions(path)
             .map_err(|e| format!("画像サイズの取得に失敗: {}", e))?;
         Ok(ratio_from_dimensions(dimensions.0, dimensions.1))
     } else {
@@ -262,56 +856,46 @@ pub async fn detect_aspect_ratio_from_slides(
     }
 }
 
-fn resolve_pdfium_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
-    let dll_name = "pdfium.dll";
-    let mut searched_paths: Vec<String> = Vec::new();
-
-    // 1. Tauri resource_dir (production NSIS/MSI build)
-    if let Ok(resource_dir) = app.path().resource_dir() {
-        let path = resource_dir.join(dll_name);
-        searched_paths.push(format!("resource_dir: {}", path.display()));
-        if path.exists() {
-            return Ok(path);
-        }
+/// Conventional names, preferred over their reduced forms (16:10 rather than 8:5).
+const COMMON_RATIOS: &[(u32, u32)] = &[(16, 9), (4, 3), (16, 10), (3, 2), (21, 9), (32, 9), (1, 1)];
+/// Largest term tried when looking for another simple ratio.
+const MAX_RATIO_TERM: u32 = 32;
+/// Relative error accepted when snapping to a simple ratio; covers pixel rounding
+/// but not near-misses like A4 (1:√2) vs 17:12.
+const RATIO_TOLERANCE: f64 = 0.001;
+
+/// Express image dimensions as a `"W:H"` ratio: a conventional or simple ratio
+/// if one matches within rounding error, otherwise the exact pixel ratio reduced
+/// by its GCD.
+pub fn ratio_from_dimensions(width: u32, height: u32) -> String {
+    if width == 0 || height == 0 {
+        return "16:9".to_string();
     }
+    let ratio = width as f64 / height as f64;
+    let matches = |w: u32, h: u32| ((w as f64 / h as f64 - ratio) / ratio).abs() < RATIO_TOLERANCE;
 
-    // 2. Next to the executable
-    if let Ok(exe_path) = std::env::current_exe() {
-        if let Some(exe_dir) = exe_path.parent() {
-            let path = exe_dir.join(dll_name);
-            searched_paths.push(format!("exe_dir: {}", path.display()));
-            if path.exists() {
-                return Ok(path);
-            }
+    for &(long, short) in COMMON_RATIOS {
+        let (w, h) = if width >= height { (long, short) } else { (short, long) };
+        if matches(w, h) {
+            return format!("{}:{}", w, h);
         }
     }
 
-    // 3. Development: src-tauri/resources/pdfium.dll
-    let dev_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-        .join("resources")
-        .join(dll_name);
-    searched_paths.push(format!("dev: {}", dev_path.display()));
-    if dev_path.exists() {
-        return Ok(dev_path);
+    for h in 1..=MAX_RATIO_TERM {
+        let w = (ratio * h as f64).round() as u32;
+        if (1..=MAX_RATIO_TERM).contains(&w) && matches(w, h) {
+            return format!("{}:{}", w, h);
+        }
     }
 
-    Err(format!(
-        "pdfium.dll が見つかりません。\n\
-         検索パス:\n{}",
-        searched_paths.join("\n")
-    ))
+    let divisor = gcd(width, height);
+    format!("{}:{}", width / divisor, height / divisor)
 }
 
-fn ratio_from_dimensions(width: u32, height: u32) -> String {
-    if height == 0 {
-        return "16:9".to_string();
-    }
-    let ratio = width as f64 / height as f64;
-    if (ratio - 16.0 / 9.0).abs() < 0.1 {
-        "16:9".to_string()
-    } else if (ratio - 4.0 / 3.0).abs() < 0.1 {
-        "4:3".to_string()
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 {
+        a
     } else {
-        "16:9".to_string()
+        gcd(b, a % b)
     }
 }
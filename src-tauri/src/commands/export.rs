@@ -73,7 +73,7 @@ pub async fn export_html(
     let mut main_html = String::new();
     for (i, slide) in main_slides.iter().enumerate() {
         let base64_img = read_and_encode_image(&project_dir, &slide.image_path).await?;
-        main_html.push_str(&render_main_slide(slide, &base64_img, &aspect_ratio_css, &all_slides));
+        main_html.push_str(&render_main_slide(slide, &base64_img, &project.aspect_ratio, &all_slides));
 
         app.emit(
             "export-progress",
@@ -93,7 +93,7 @@ pub async fn export_html(
         }
         if !slide.is_main {
             let base64_img = read_and_encode_image(&project_dir, &slide.image_path).await?;
-            sub_html.push_str(&render_sub_slide(slide, &base64_img, &aspect_ratio_css, &all_slides));
+            sub_html.push_str(&render_sub_slide(slide, &base64_img, &project.aspect_ratio, &all_slides));
             processed += 1;
             app.emit(
                 "export-progress",
@@ -116,7 +116,7 @@ pub async fn export_html(
         });
         if !slide.hotspots.is_empty() || is_hotspot_target || is_graph_link_target || !slide.graph_links.is_empty() {
             let base64_img = read_and_encode_image(&project_dir, &slide.image_path).await?;
-            sub_html.push_str(&render_sub_slide(slide, &base64_img, &aspect_ratio_css, &all_slides));
+            sub_html.push_str(&render_sub_slide(slide, &base64_img, &project.aspect_ratio, &all_slides));
         }
     }
 
@@ -164,7 +164,7 @@ async fn read_and_encode_image(project_dir: &str, image_path: &str) -> Result<St
     Ok(base64::engine::general_purpose::STANDARD.encode(&bytes))
 }
 
-fn render_main_slide(slide: &Slide, base64_img: &str, aspect_ratio: &str, all_slides: &[&Slide]) -> String {
+fn render_main_slide(slide: &Slide, base64_img: &str, project_ratio: &str, all_slides: &[&Slide]) -> String {
     format!(
         r#"  <section class="main-slide" id="{id}">
     <div class="slide-container" style="position:relative;width:100%;aspect-ratio:{ar};">
@@ -176,7 +176,7 @@ fn render_main_slide(slide: &Slide, base64_img: &str, aspect_ratio: &str, all_sl
   </section>
 "#,
         id = slide.id,
-        ar = aspect_ratio,
+        ar = slide.aspect_ratio_css(project_ratio),
         img = base64_img,
         label = html_escape(&slide.label),
         hotspots = render_hotspots(&slide.hotspots),
@@ -185,11 +185,11 @@ fn render_main_slide(slide: &Slide, base64_img: &str, aspect_ratio: &str, all_sl
     )
 }
 
-fn render_sub_slide(slide: &Slide, base64_img: &str, aspect_ratio: &str, all_slides: &[&Slide]) -> String {
+fn render_sub_slide(slide: &Slide, base64_img: &str, project_ratio: &str, all_slides: &[&Slide]) -> String {
     format!(
         r#"  <div class="modal-overlay" id="modal-{id}">
     <button class="back-btn" onclick="event.stopPropagation();goBack()">&#8592; 戻る</button>
-    <div class="modal-content" onclick="event.stopPropagation()" style="position:relative;aspect-ratio:{ar};">
+    <div class="modal-content" onclick="event.stopPropagation()" style="position:relative;aspect-ratio:{ar};max-width:min(1200px, calc(90vh * {ratio:.4}));">
       <img src="data:image/png;base64,{img}" alt="{label}" />
       <div class="hotspot-layer">{hotspots}</div>
       {text_overlays}
@@ -198,7 +198,8 @@ fn render_sub_slide(slide: &Slide, base64_img: &str, aspect_ratio: &str, all_sli
   </div>
 "#,
         id = slide.id,
-        ar = aspect_ratio,
+        ar = slide.aspect_ratio_css(project_ratio),
+        ratio = slide.aspect_ratio_value(project_ratio),
         img = base64_img,
         label = html_escape(&slide.label),
         hotspots = render_hotspots(&slide.hotspots),
@@ -10,6 +10,22 @@ struct SlideImageData {
     rgb_pixels: Vec<u8>,
 }
 
+/// Length of a page's longer edge. The shorter edge follows the slide's own
+/// aspect ratio, so decks mixing portrait and landscape pages stay undistorted.
+const PAGE_LONG_EDGE_MM: f32 = 340.0;
+
+fn page_size_mm(width: u32, height: u32) -> (Mm, Mm) {
+    if width == 0 || height == 0 {
+        return (Mm(PAGE_LONG_EDGE_MM), Mm(PAGE_LONG_EDGE_MM * 9.0 / 16.0));
+    }
+    let ratio = width as f32 / height as f32;
+    if ratio >= 1.0 {
+        (Mm(PAGE_LONG_EDGE_MM), Mm(PAGE_LONG_EDGE_MM / ratio))
+    } else {
+        (Mm(PAGE_LONG_EDGE_MM * ratio), Mm(PAGE_LONG_EDGE_MM))
+    }
+}
+
 #[tauri::command]
 pub async fn export_pdf(
     app: tauri::AppHandle,
@@ -60,20 +76,20 @@ pub async fn export_pdf(
     }
 
     // Phase 2: Build PDF synchronously (PdfDocumentReference is !Send)
-    let aspect_ratio = project.aspect_ratio.clone();
     let source_file = project.source_file.clone();
 
     let pdf_bytes = tokio::task::spawn_blocking(move || -> Result<Vec<u8>, String> {
-        let (page_w_mm, page_h_mm) = if aspect_ratio == "4:3" {
-            (Mm(297.0), Mm(222.75))
-        } else {
-            (Mm(340.0), Mm(191.25))
-        };
+        // Each page is sized from its own image
+        let (first_w_mm, first_h_mm) = slide_images
+            .first()
+            .map(|img| page_size_mm(img.width, img.height))
+            .unwrap_or_else(|| page_size_mm(16, 9));
 
         let (doc, page1, layer1) =
-            PdfDocument::new(&source_file, page_w_mm, page_h_mm, "Layer 1");
+            PdfDocument::new(&source_file, first_w_mm, first_h_mm, "Layer 1");
 
         for (i, img_data) in slide_images.iter().enumerate() {
+            let (page_w_mm, page_h_mm) = page_size_mm(img_data.width, img_data.height);
             let (current_page, current_layer) = if i == 0 {
                 (page1, layer1)
             } else {
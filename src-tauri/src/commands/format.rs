@@ -0,0 +1,128 @@
+//! Identifies source files by their content rather than their extension, so
+//! renamed files and the less common presentation formats are routed correctly.
+
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use zip::ZipArchive;
+
+const OLE2_MAGIC: &[u8] = &[0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
+const ZIP_MAGIC: &[u8] = b"PK\x03\x04";
+const ODP_MIMETYPE: &str = "application/vnd.oasis.opendocument.presentation";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceFormat {
+    Pdf,
+    /// Office Open XML presentation (.pptx, .ppsx, .potx, .pptm). Converted by
+    /// LibreOffice; links, notes and sections are read from the package itself.
+    OoxmlPresentation,
+    /// Any other presentation LibreOffice can open: .ppt/.pps/.pot (OLE2),
+    /// .odp/.fodp and Keynote .key.
+    OtherPresentation,
+}
+
+impl SourceFormat {
+    pub fn needs_libreoffice(self) -> bool {
+        self != SourceFormat::Pdf
+    }
+}
+
+fn unsupported(path: &Path) -> String {
+    format!(
+        "対応していないファイル形式です: {}\n\n\
+         対応形式: PDF / PowerPoint (pptx, ppsx, potx, ppt, pps) / \
+         OpenDocument (odp) / Keynote (key)",
+        path.file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.display().to_string())
+    )
+}
+
+pub fn detect_format(path: &Path) -> Result<SourceFormat, String> {
+    if path.is_dir() {
+        return Err(unsupported(path));
+    }
+
+    let mut header = Vec::with_capacity(4096);
+    File::open(path)
+        .and_then(|file| file.take(4096).read_to_end(&mut header))
+        .map_err(|e| format!("ファイルを開けません: {}", e))?;
+
+    // The PDF header may be preceded by a few bytes of garbage
+    if header.windows(5).take(1024).any(|w| w == b"%PDF-") {
+        return Ok(SourceFormat::Pdf);
+    }
+    if header.starts_with(ZIP_MAGIC) {
+        return detect_zip_format(path);
+    }
+    if header.starts_with(OLE2_MAGIC) {
+        // Word and Excel binaries share the container; only PowerPoint has this stream
+        return match file_contains(path, &utf16le("PowerPoint Document")) {
+            Ok(true) => Ok(SourceFormat::OtherPresentation),
+            _ => Err(unsupported(path)),
+        };
+    }
+    // Flat OpenDocument presentation (.fodp)
+    let text = String::from_utf8_lossy(&header);
+    if text.trim_start().starts_with("<?xml") && text.contains(ODP_MIMETYPE) {
+        return Ok(SourceFormat::OtherPresentation);
+    }
+
+    Err(unsupported(path))
+}
+
+/// Tell presentation packages apart by their manifest entries.
+fn detect_zip_format(path: &Path) -> Result<SourceFormat, String> {
+    let file = File::open(path).map_err(|e| format!("ファイルを開けません: {}", e))?;
+    let mut archive = ZipArchive::new(file).map_err(|_| unsupported(path))?;
+
+    if archive.by_name("ppt/presentation.xml").is_ok() {
+        return Ok(SourceFormat::OoxmlPresentation);
+    }
+
+    if let Ok(mut mimetype) = archive.by_name("mimetype") {
+        let mut content = String::new();
+        if mimetype.read_to_string(&mut content).is_ok() && content.starts_with(ODP_MIMETYPE) {
+            return Ok(SourceFormat::OtherPresentation);
+        }
+        return Err(unsupported(path));
+    }
+
+    // Keynote 6+ (IWA) and Keynote '09 (APXL) packages
+    let is_keynote = archive.file_names().any(|name| {
+        name == "Index.zip"
+            || name == "index.apxl"
+            || (name.starts_with("Index/") && name.ends_with(".iwa"))
+    });
+    if is_keynote {
+        return Ok(SourceFormat::OtherPresentation);
+    }
+
+    Err(unsupported(path))
+}
+
+fn utf16le(text: &str) -> Vec<u8> {
+    text.encode_utf16().flat_map(|unit| unit.to_le_bytes()).collect()
+}
+
+/// Search a file for a byte sequence without loading it into memory at once.
+fn file_contains(path: &Path, needle: &[u8]) -> std::io::Result<bool> {
+    const CHUNK: usize = 1 << 20;
+    let mut file = File::open(path)?;
+    let mut window: Vec<u8> = Vec::with_capacity(CHUNK + needle.len());
+    let mut buf = vec![0u8; CHUNK];
+    loop {
+        let read = file.read(&mut buf)?;
+        if read == 0 {
+            return Ok(false);
+        }
+        window.extend_from_slice(&buf[..read]);
+        if window.windows(needle.len()).any(|w| w == needle) {
+            return Ok(true);
+        }
+        // Keep the tail so matches spanning two chunks are still found
+        let keep = needle.len().saturating_sub(1).min(window.len());
+        window.drain(..window.len() - keep);
+    }
+}
@@ -0,0 +1,252 @@
+//! Imports a folder or .zip of exported slide images (PNG/JPEG) as slides,
+//! for decks that arrive as image sequences instead of a PDF.
+
+use std::cmp::Ordering;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use tauri::Emitter;
+use zip::ZipArchive;
+
+use crate::models::{ConvertOptions, ConvertProgress, SlideInfo};
+use super::convert::{ratio_from_dimensions, slide_id, truncate_label};
+use super::rasterize::PageSelection;
+
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg"];
+
+/// Whether `path` is a source this module imports (a directory or a .zip archive).
+pub fn is_image_sequence(path: &Path) -> bool {
+    path.is_dir()
+        || path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("zip"))
+}
+
+/// One image of the sequence, read lazily so large archives are not held in memory.
+enum ImageEntry {
+    File(PathBuf),
+    Zipped(usize),
+}
+
+struct ImageSource {
+    /// File names in natural order, paired with where to read them from.
+    entries: Vec<(String, ImageEntry)>,
+    archive: Option<ZipArchive<File>>,
+}
+
+impl ImageSource {
+    fn open(path: &Path) -> Result<Self, String> {
+        if path.is_dir() {
+            let read_dir = std::fs::read_dir(path)
+                .map_err(|e| format!("フォルダの読み込みに失敗: {}", e))?;
+            let entries = read_dir
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+                .filter(|path| path.is_file())
+                .filter_map(|path| {
+                    let name = path.file_name()?.to_str()?.to_string();
+                    Some((name, ImageEntry::File(path)))
+                })
+                .collect();
+            return Ok(ImageSource::sorted(entries, None));
+        }
+
+        let file = File::open(path).map_err(|e| format!("ZIP ファイルを開けません: {}", e))?;
+        let archive =
+            ZipArchive::new(file).map_err(|e| format!("ZIP ファイルの読み取りに失敗: {}", e))?;
+        let entries = (0..archive.len())
+            .filter_map(|i| {
+                let name = archive.name_for_index(i)?;
+                // Skip folders and the resource forks macOS adds when zipping
+                if name.ends_with('/') || name.starts_with("__MACOSX/") {
+                    return None;
+                }
+                let file_name = name.rsplit('/').next()?.to_string();
+                Some((file_name, ImageEntry::Zipped(i)))
+            })
+            .collect();
+        Ok(ImageSource::sorted(entries, Some(archive)))
+    }
+
+    fn sorted(entries: Vec<(String, ImageEntry)>, archive: Option<ZipArchive<File>>) -> Self {
+        let mut entries: Vec<(String, ImageEntry)> = entries
+            .into_iter()
+            .filter(|(name, _)| !name.starts_with('.') && is_image_name(name))
+            .collect();
+        entries.sort_by(|(a, _), (b, _)| natural_cmp(a, b));
+        ImageSource { entries, archive }
+    }
+
+    fn read(&mut self, index: usize) -> Result<Vec<u8>, String> {
+        let (name, entry) = &self.entries[index];
+        match entry {
+            ImageEntry::File(path) => {
+                std::fs::read(path).map_err(|e| format!("画像の読み込みに失敗 ({}): {}", name, e))
+            }
+            ImageEntry::Zipped(zip_index) => {
+                let archive = self.archive.as_mut().ok_or("ZIP ファイルが開かれていません")?;
+                let mut file = archive
+                    .by_index(*zip_index)
+                    .map_err(|e| format!("画像の読み込みに失敗 ({}): {}", name, e))?;
+                let mut bytes = Vec::new();
+                file.read_to_end(&mut bytes)
+                    .map_err(|e| format!("画像の読み込みに失敗 ({}): {}", name, e))?;
+                Ok(bytes)
+            }
+        }
+    }
+}
+
+fn is_image_name(name: &str) -> bool {
+    name.rsplit_once('.').is_some_and(|(_, ext)| {
+        IMAGE_EXTENSIONS
+            .iter()
+            .any(|known| ext.eq_ignore_ascii_case(known))
+    })
+}
+
+/// Compare file names so that "slide2" sorts before "slide10".
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a_chars = a.chars().peekable();
+    let mut b_chars = b.chars().peekable();
+    loop {
+        match (a_chars.peek().copied(), b_chars.peek().copied()) {
+            (None, None) => return a.cmp(b),
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let take_number = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+                    let mut digits = String::new();
+                    while let Some(c) = chars.next_if(char::is_ascii_digit) {
+                        digits.push(c);
+                    }
+                    digits
+                };
+                let x_digits = take_number(&mut a_chars);
+                let y_digits = take_number(&mut b_chars);
+                let x_trimmed = x_digits.trim_start_matches('0');
+                let y_trimmed = y_digits.trim_start_matches('0');
+                let ordering = x_trimmed
+                    .len()
+                    .cmp(&y_trimmed.len())
+                    .then_with(|| x_trimmed.cmp(y_trimmed));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(x), Some(y)) => {
+                let ordering = x.to_lowercase().cmp(y.to_lowercase());
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+                a_chars.next();
+                b_chars.next();
+            }
+        }
+    }
+}
+
+/// Import every PNG/JPEG in a folder or .zip as a slide, in natural file-name order.
+/// `options.pages` selects images by their 1-based position; render resolution
+/// options do not apply to images that are already rasterized.
+#[tauri::command]
+pub async fn import_image_sequence(
+    app: tauri::AppHandle,
+    source_path: String,
+    output_dir: String,
+    start_index: usize,
+    options: Option<ConvertOptions>,
+) -> Result<Vec<SlideInfo>, String> {
+    let output_path = PathBuf::from(&output_dir);
+    std::fs::create_dir_all(&output_path)
+        .map_err(|e| format!("出力ディレクトリの作成に失敗: {}", e))?;
+
+    let mut source = ImageSource::open(Path::new(&source_path))?;
+    if source.entries.is_empty() {
+        return Err("PNG / JPEG 画像が見つかりません".to_string());
+    }
+
+    let selected: Vec<usize> = match options
+        .and_then(|options| options.pages)
+        .filter(|spec| !spec.trim().is_empty())
+    {
+        Some(spec) => PageSelection::parse(&spec)?.resolve(source.entries.len())?,
+        None => (0..source.entries.len()).collect(),
+    };
+    let total = selected.len();
+
+    app.emit(
+        "convert-progress",
+        ConvertProgress {
+            current: 0,
+            total,
+            message: "画像を取り込み中...".to_string(),
+        },
+    )
+    .ok();
+
+    let mut slides: Vec<SlideInfo> = Vec::with_capacity(total);
+    for (done, &position) in selected.iter().enumerate() {
+        let bytes = source.read(position)?;
+        let name = &source.entries[position].0;
+        let image = image::load_from_memory(&bytes)
+            .map_err(|e| format!("画像のデコードに失敗 ({}): {}", name, e))?
+            .into_rgba8();
+
+        // Positions are kept as-is so a partial import still numbers slides by file order
+        let global_index = start_index + position;
+        let file_name = format!("slide-{:03}.png", global_index + 1);
+        image
+            .save(output_path.join(&file_name))
+            .map_err(|e| format!("画像の保存に失敗 ({}): {}", file_name, e))?;
+
+        let label = name
+            .rsplit_once('.')
+            .map(|(stem, _)| stem)
+            .filter(|stem| !stem.trim().is_empty())
+            .map(truncate_label)
+            .unwrap_or_else(|| format!("Slide {}", global_index + 1));
+        let (width, height) = image.dimensions();
+        slides.push(SlideInfo {
+            id: slide_id(global_index),
+            index: global_index,
+            label,
+            image_path: format!("slides/{}", file_name),
+            hotspots: Vec::new(),
+            source_page: position,
+            width,
+            height,
+            aspect_ratio: ratio_from_dimensions(width, height),
+            text_layer: None,
+            notes: None,
+            is_main: true,
+            enabled: true,
+            section: None,
+            graph_links: Vec::new(),
+        });
+
+        app.emit(
+            "convert-progress",
+            ConvertProgress {
+                current: done + 1,
+                total,
+                message: format!("取り込み中... ({}/{})", done + 1, total),
+            },
+        )
+        .ok();
+    }
+
+    app.emit(
+        "convert-progress",
+        ConvertProgress {
+            current: total,
+            total,
+            message: "取り込み完了".to_string(),
+        },
+    )
+    .ok();
+
+    Ok(slides)
+}
@@ -1,6 +1,10 @@
 pub mod convert;
 pub mod export;
 pub mod export_pdf;
+pub mod format;
 pub mod image;
+pub mod image_sequence;
+pub mod pptx;
 pub mod project;
+pub mod rasterize;
 pub mod settings;
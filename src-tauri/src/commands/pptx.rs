@@ -0,0 +1,539 @@
+//! Reads interactive metadata straight from a PPTX package (zip + DrawingML).
+//! LibreOffice drops most shape-level click actions when it flattens a deck
+//! into a PDF, so these are recovered from the source file instead.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use roxmltree::{Document, Node};
+use zip::ZipArchive;
+
+use super::rasterize::{LinkTarget, PageLink};
+
+const R_NS: &str = "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
+
+pub struct PptxDeck {
+    /// Slides in presentation order (the order LibreOffice exports pages in).
+    pub slides: Vec<PptxSlide>,
+}
+
+#[derive(Default)]
+pub struct PptxSlide {
+    pub links: Vec<PageLink>,
+    /// Text of the title placeholder, if the slide has a non-empty one.
+    pub title: Option<String>,
+    /// Speaker notes, one line per paragraph.
+    pub notes: Option<String>,
+    /// Marked hidden (`show="0"`) in PowerPoint.
+    pub hidden: bool,
+    /// Name of the section the slide belongs to, if the deck uses sections.
+    pub section: Option<String>,
+}
+
+struct Relationship {
+    target: String,
+    rel_type: String,
+    external: bool,
+}
+
+type Relationships = HashMap<String, Relationship>;
+
+/// Maps shape coordinates (EMU) to slide coordinates, accumulating group transforms.
+#[derive(Clone, Copy)]
+struct Transform {
+    scale_x: f64,
+    offset_x: f64,
+    scale_y: f64,
+    offset_y: f64,
+}
+
+impl Transform {
+    const IDENTITY: Transform = Transform {
+        scale_x: 1.0,
+        offset_x: 0.0,
+        scale_y: 1.0,
+        offset_y: 0.0,
+    };
+
+    fn apply(&self, x: f64, y: f64) -> (f64, f64) {
+        (self.scale_x * x + self.offset_x, self.scale_y * y + self.offset_y)
+    }
+
+    /// Compose with a group's `a:xfrm`, which maps its child extents onto its own extents.
+    fn then_group(&self, xfrm: &Xfrm) -> Transform {
+        let sx = if xfrm.ch_cx > 0.0 { xfrm.cx / xfrm.ch_cx } else { 1.0 };
+        let sy = if xfrm.ch_cy > 0.0 { xfrm.cy / xfrm.ch_cy } else { 1.0 };
+        Transform {
+            scale_x: self.scale_x * sx,
+            offset_x: self.scale_x * (xfrm.x - xfrm.ch_x * sx) + self.offset_x,
+            scale_y: self.scale_y * sy,
+            offset_y: self.scale_y * (xfrm.y - xfrm.ch_y * sy) + self.offset_y,
+        }
+    }
+}
+
+struct Xfrm {
+    x: f64,
+    y: f64,
+    cx: f64,
+    cy: f64,
+    ch_x: f64,
+    ch_y: f64,
+    ch_cx: f64,
+    ch_cy: f64,
+    /// Rotation in degrees.
+    rotation: f64,
+}
+
+/// Everything needed to resolve links on one slide.
+struct SlideContext<'a> {
+    rels: &'a Relationships,
+    part_dir: &'a str,
+    slide_order: &'a HashMap<String, usize>,
+    current: usize,
+    slide_count: usize,
+    layouts: &'a [Document<'a>],
+    slide_width: f64,
+    slide_height: f64,
+}
+
+pub fn read_pptx(path: &Path) -> Result<PptxDeck, String> {
+    let file = File::open(path).map_err(|e| format!("PPTX ファイルを開けません: {}", e))?;
+    let mut archive =
+        ZipArchive::new(file).map_err(|e| format!("PPTX パッケージの読み取りに失敗: {}", e))?;
+
+    let presentation_xml = read_part(&mut archive, "ppt/presentation.xml")
+        .ok_or("ppt/presentation.xml が見つかりません")?;
+    let presentation = Document::parse(&presentation_xml)
+        .map_err(|e| format!("presentation.xml の解析に失敗: {}", e))?;
+    let presentation_rels = read_rels(&mut archive, "ppt/presentation.xml");
+
+    let (slide_width, slide_height) = presentation
+        .descendants()
+        .find(|n| is_tag(n, "sldSz"))
+        .map(|n| (attr_f64(n, "cx"), attr_f64(n, "cy")))
+        .filter(|(w, h)| *w > 0.0 && *h > 0.0)
+        .unwrap_or((12_192_000.0, 6_858_000.0));
+
+    // Section lists also contain `sldId` elements, but only the slide list's carry `r:id`
+    let (slide_ids, slide_parts): (Vec<&str>, Vec<String>) = presentation
+        .descendants()
+        .filter(|n| is_tag(n, "sldId"))
+        .filter_map(|n| {
+            let rel = presentation_rels.get(n.attribute((R_NS, "id"))?)?;
+            Some((n.attribute("id").unwrap_or_default(), resolve_part("ppt", &rel.target)))
+        })
+        .unzip();
+    let sections = read_sections(&presentation);
+
+    let slide_order: HashMap<String, usize> = slide_parts
+        .iter()
+        .enumerate()
+        .map(|(i, part)| (part.clone(), i))
+        .collect();
+
+    let mut slides = Vec::with_capacity(slide_parts.len());
+    for (index, part) in slide_parts.iter().enumerate() {
+        let section = sections.get(slide_ids[index]).cloned();
+        let Some(xml) = read_part(&mut archive, part) else {
+            slides.push(PptxSlide {
+                section,
+                ..PptxSlide::default()
+            });
+            continue;
+        };
+        let Ok(doc) = Document::parse(&xml) else {
+            slides.push(PptxSlide {
+                section,
+                ..PptxSlide::default()
+            });
+            continue;
+        };
+        let rels = read_rels(&mut archive, part);
+        let part_dir = parent_dir(part);
+
+        // Placeholders without their own xfrm inherit geometry from the layout, then the master
+        let layout_xmls = read_layout_chain(&mut archive, part, &rels);
+        let layouts: Vec<Document> = layout_xmls
+            .iter()
+            .filter_map(|xml| Document::parse(xml).ok())
+            .collect();
+
+        let ctx = SlideContext {
+            rels: &rels,
+            part_dir: &part_dir,
+            slide_order: &slide_order,
+            current: index,
+            slide_count: slide_parts.len(),
+            layouts: &layouts,
+            slide_width,
+            slide_height,
+        };
+
+        let mut links = Vec::new();
+        if let Some(tree) = doc.descendants().find(|n| is_tag(n, "spTree")) {
+            collect_links(tree, Transform::IDENTITY, &ctx, &mut links);
+        }
+        let notes = find_rel_part(part, &rels, "/notesSlide")
+            .and_then(|notes_part| read_part(&mut archive, &notes_part))
+            .and_then(|xml| notes_text(&xml));
+
+        slides.push(PptxSlide {
+            links,
+            title: slide_title(&doc),
+            notes,
+            hidden: doc.root_element().attribute("show") == Some("0"),
+            section,
+        });
+    }
+
+    Ok(PptxDeck { slides })
+}
+
+/// Map slide ids (`p:sldId/@id`) to the name of their section, from the
+/// `p14:sectionLst` extension PowerPoint 2010+ writes into presentation.xml.
+fn read_sections(presentation: &Document) -> HashMap<String, String> {
+    let mut sections = HashMap::new();
+    for section in presentation.descendants().filter(|n| is_tag(n, "section")) {
+        let name = section.attribute("name").unwrap_or_default().to_string();
+        let Some(id_list) = child(section, "sldIdLst") else { continue };
+        for id in id_list.children().filter(|n| is_tag(n, "sldId")) {
+            if let Some(id) = id.attribute("id") {
+                sections.insert(id.to_string(), name.clone());
+            }
+        }
+    }
+    sections
+}
+
+/// Text of the slide's `title` or `ctrTitle` placeholder, paragraphs joined by spaces.
+fn slide_title(doc: &Document) -> Option<String> {
+    let title_shape = find_placeholder(doc, &["title", "ctrTitle"])?;
+    let body = child(title_shape, "txBody")?;
+    let title = paragraphs(body, " ")
+        .join(" ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!title.is_empty()).then_some(title)
+}
+
+/// Text of a notes slide's `body` placeholder, keeping paragraph and line breaks.
+fn notes_text(xml: &str) -> Option<String> {
+    let doc = Document::parse(xml).ok()?;
+    let body = child(find_placeholder(&doc, &["body"])?, "txBody")?;
+    let notes = paragraphs(body, "\n").join("\n").trim().to_string();
+    (!notes.is_empty()).then_some(notes)
+}
+
+fn find_placeholder<'a, 'input>(
+    doc: &'a Document<'input>,
+    types: &[&str],
+) -> Option<Node<'a, 'input>> {
+    doc.descendants().filter(|n| is_tag(n, "sp")).find(|sp| {
+        child(*sp, "nvSpPr")
+            .and_then(|nv| child(nv, "nvPr"))
+            .and_then(|nv| child(nv, "ph"))
+            .and_then(|ph| ph.attribute("type"))
+            .is_some_and(|ph_type| types.contains(&ph_type))
+    })
+}
+
+/// Plain text of each `a:p` in a text body, with `a:br` replaced by `line_break`.
+fn paragraphs(body: Node, line_break: &str) -> Vec<String> {
+    body.children()
+        .filter(|n| is_tag(n, "p"))
+        .map(|p| {
+            p.descendants()
+                .filter(|n| is_tag(n, "t") || is_tag(n, "br"))
+                .map(|n| if is_tag(&n, "br") { line_break } else { n.text().unwrap_or_default() })
+                .collect::<String>()
+        })
+        .collect()
+}
+
+fn collect_links(parent: Node, transform: Transform, ctx: &SlideContext, links: &mut Vec<PageLink>) {
+    for shape in parent.children().filter(|n| n.is_element()) {
+        match shape.tag_name().name() {
+            "grpSp" => {
+                let group_transform = child(shape, "grpSpPr")
+                    .and_then(|pr| child(pr, "xfrm"))
+                    .and_then(parse_xfrm)
+                    .map(|xfrm| transform.then_group(&xfrm))
+                    .unwrap_or(transform);
+                if let Some(link) = shape_link(shape, transform, ctx) {
+                    links.push(link);
+                }
+                collect_links(shape, group_transform, ctx, links);
+            }
+            "sp" | "pic" | "cxnSp" | "graphicFrame" => {
+                if let Some(link) = shape_link(shape, transform, ctx) {
+                    links.push(link);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn shape_link(shape: Node, transform: Transform, ctx: &SlideContext) -> Option<PageLink> {
+    let non_visual = shape
+        .children()
+        .find(|n| n.is_element() && n.tag_name().name().starts_with("nv"))?;
+    let hlink = child(non_visual, "cNvPr").and_then(|pr| child(pr, "hlinkClick"))?;
+    let target = resolve_action(hlink, ctx)?;
+
+    let xfrm = shape_xfrm(shape).or_else(|| {
+        let ph = child(non_visual, "nvPr").and_then(|pr| child(pr, "ph"))?;
+        placeholder_xfrm(ph, ctx.layouts)
+    })?;
+    let bounds = to_percent(&xfrm, transform, ctx.slide_width, ctx.slide_height)?;
+
+    Some(PageLink { bounds, target })
+}
+
+fn resolve_action(hlink: Node, ctx: &SlideContext) -> Option<LinkTarget> {
+    let action = hlink.attribute("action").unwrap_or("");
+    let rel = hlink
+        .attribute((R_NS, "id"))
+        .filter(|id| !id.is_empty())
+        .and_then(|id| ctx.rels.get(id));
+
+    if let Some(jump) = action.strip_prefix("ppaction://hlinkshowjump?jump=") {
+        let last = ctx.slide_count.checked_sub(1)?;
+        let page = match jump {
+            "firstslide" => 0,
+            "lastslide" => last,
+            "nextslide" => (ctx.current < last).then_some(ctx.current + 1)?,
+            "previousslide" => ctx.current.checked_sub(1)?,
+            _ => return None,
+        };
+        return Some(LinkTarget::Page(page));
+    }
+
+    match action {
+        "ppaction://hlinksldjump" => {
+            let rel = rel.filter(|r| !r.external)?;
+            let part = resolve_part(ctx.part_dir, &rel.target);
+            ctx.slide_order.get(&part).map(|&page| LinkTarget::Page(page))
+        }
+        "" => {
+            let rel = rel.filter(|r| r.external && !r.target.trim().is_empty())?;
+            Some(LinkTarget::Url(rel.target.clone()))
+        }
+        // Macros, programs, custom shows, OLE verbs etc. have no HTML equivalent
+        _ => None,
+    }
+}
+
+fn shape_xfrm(shape: Node) -> Option<Xfrm> {
+    let xfrm = match shape.tag_name().name() {
+        "graphicFrame" => child(shape, "xfrm"),
+        "grpSp" => child(shape, "grpSpPr").and_then(|pr| child(pr, "xfrm")),
+        _ => child(shape, "spPr").and_then(|pr| child(pr, "xfrm")),
+    }?;
+    parse_xfrm(xfrm)
+}
+
+/// Find the geometry of a placeholder in the slide's layout or master.
+fn placeholder_xfrm(ph: Node, layouts: &[Document]) -> Option<Xfrm> {
+    let ph_idx = ph.attribute("idx");
+    let ph_type = normalize_ph_type(ph.attribute("type"));
+
+    layouts.iter().find_map(|doc| {
+        let candidates: Vec<(Node, Node)> = doc
+            .descendants()
+            .filter(|n| is_tag(n, "sp"))
+            .filter_map(|sp| {
+                let ph = sp
+                    .children()
+                    .find(|n| n.is_element() && n.tag_name().name().starts_with("nv"))
+                    .and_then(|nv| child(nv, "nvPr"))
+                    .and_then(|pr| child(pr, "ph"))?;
+                Some((sp, ph))
+            })
+            .collect();
+
+        let by_idx = ph_idx.and_then(|idx| {
+            candidates
+                .iter()
+                .find(|(_, candidate)| candidate.attribute("idx") == Some(idx))
+        });
+        let by_type = || {
+            candidates
+                .iter()
+                .find(|(_, candidate)| normalize_ph_type(candidate.attribute("type")) == ph_type)
+        };
+        by_idx
+            .or_else(by_type)
+            .and_then(|(sp, _)| shape_xfrm(*sp))
+    })
+}
+
+/// Collapse placeholder types the way layouts and masters are matched by PowerPoint.
+fn normalize_ph_type(ph_type: Option<&str>) -> &str {
+    match ph_type {
+        Some("title") | Some("ctrTitle") => "title",
+        Some("dt") => "dt",
+        Some("ftr") => "ftr",
+        Some("sldNum") => "sldNum",
+        _ => "body",
+    }
+}
+
+fn parse_xfrm(xfrm: Node) -> Option<Xfrm> {
+    let off = child(xfrm, "off")?;
+    let ext = child(xfrm, "ext")?;
+    let ch_off = child(xfrm, "chOff");
+    let ch_ext = child(xfrm, "chExt");
+    Some(Xfrm {
+        x: attr_f64(off, "x"),
+        y: attr_f64(off, "y"),
+        cx: attr_f64(ext, "cx"),
+        cy: attr_f64(ext, "cy"),
+        ch_x: ch_off.map(|n| attr_f64(n, "x")).unwrap_or(0.0),
+        ch_y: ch_off.map(|n| attr_f64(n, "y")).unwrap_or(0.0),
+        ch_cx: ch_ext.map(|n| attr_f64(n, "cx")).unwrap_or(0.0),
+        ch_cy: ch_ext.map(|n| attr_f64(n, "cy")).unwrap_or(0.0),
+        rotation: attr_f64(xfrm, "rot") / 60_000.0,
+    })
+}
+
+/// Axis-aligned bounding box of the (possibly rotated) shape, as slide percentages.
+fn to_percent(
+    xfrm: &Xfrm,
+    transform: Transform,
+    slide_width: f64,
+    slide_height: f64,
+) -> Option<(f64, f64, f64, f64)> {
+    let (x0, y0) = transform.apply(xfrm.x, xfrm.y);
+    let (x1, y1) = transform.apply(xfrm.x + xfrm.cx, xfrm.y + xfrm.cy);
+    let (w, h) = ((x1 - x0).abs(), (y1 - y0).abs());
+    let (center_x, center_y) = ((x0 + x1) / 2.0, (y0 + y1) / 2.0);
+
+    let (sin, cos) = xfrm.rotation.to_radians().sin_cos();
+    let half_w = (w * cos.abs() + h * sin.abs()) / 2.0;
+    let half_h = (w * sin.abs() + h * cos.abs()) / 2.0;
+
+    let left = ((center_x - half_w) / slide_width).clamp(0.0, 1.0);
+    let right = ((center_x + half_w) / slide_width).clamp(0.0, 1.0);
+    let top = ((center_y - half_h) / slide_height).clamp(0.0, 1.0);
+    let bottom = ((center_y + half_h) / slide_height).clamp(0.0, 1.0);
+
+    if right <= left || bottom <= top {
+        return None;
+    }
+    Some((
+        left * 100.0,
+        top * 100.0,
+        (right - left) * 100.0,
+        (bottom - top) * 100.0,
+    ))
+}
+
+/// Read the slide's layout and that layout's master, in lookup order.
+fn read_layout_chain(
+    archive: &mut ZipArchive<File>,
+    slide_part: &str,
+    slide_rels: &Relationships,
+) -> Vec<String> {
+    let mut chain = Vec::new();
+    let Some(layout_part) = find_rel_part(slide_part, slide_rels, "/slideLayout") else {
+        return chain;
+    };
+    if let Some(xml) = read_part(archive, &layout_part) {
+        chain.push(xml);
+    }
+    let layout_rels = read_rels(archive, &layout_part);
+    if let Some(master_part) = find_rel_part(&layout_part, &layout_rels, "/slideMaster") {
+        if let Some(xml) = read_part(archive, &master_part) {
+            chain.push(xml);
+        }
+    }
+    chain
+}
+
+fn find_rel_part(part: &str, rels: &Relationships, type_suffix: &str) -> Option<String> {
+    rels.values()
+        .find(|rel| !rel.external && rel.rel_type.ends_with(type_suffix))
+        .map(|rel| resolve_part(&parent_dir(part), &rel.target))
+}
+
+fn read_part(archive: &mut ZipArchive<File>, name: &str) -> Option<String> {
+    let mut entry = archive.by_name(name).ok()?;
+    let mut content = String::new();
+    entry.read_to_string(&mut content).ok()?;
+    Some(content)
+}
+
+/// Parse `<dir>/_rels/<file>.rels` for the given part. Missing rels mean no relationships.
+fn read_rels(archive: &mut ZipArchive<File>, part: &str) -> Relationships {
+    let (dir, file) = part.rsplit_once('/').unwrap_or(("", part));
+    let rels_name = if dir.is_empty() {
+        format!("_rels/{}.rels", file)
+    } else {
+        format!("{}/_rels/{}.rels", dir, file)
+    };
+    let Some(xml) = read_part(archive, &rels_name) else {
+        return Relationships::new();
+    };
+    let Ok(doc) = Document::parse(&xml) else {
+        return Relationships::new();
+    };
+    doc.descendants()
+        .filter(|n| is_tag(n, "Relationship"))
+        .filter_map(|n| {
+            Some((
+                n.attribute("Id")?.to_string(),
+                Relationship {
+                    target: n.attribute("Target")?.to_string(),
+                    rel_type: n.attribute("Type").unwrap_or("").to_string(),
+                    external: n.attribute("TargetMode") == Some("External"),
+                },
+            ))
+        })
+        .collect()
+}
+
+fn parent_dir(part: &str) -> String {
+    part.rsplit_once('/')
+        .map(|(dir, _)| dir.to_string())
+        .unwrap_or_default()
+}
+
+/// Resolve a relationship target relative to the directory of its source part.
+fn resolve_part(base_dir: &str, target: &str) -> String {
+    let joined = match target.strip_prefix('/') {
+        Some(absolute) => absolute.to_string(),
+        None if base_dir.is_empty() => target.to_string(),
+        None => format!("{}/{}", base_dir, target),
+    };
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in joined.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            s => segments.push(s),
+        }
+    }
+    segments.join("/")
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, local_name: &str) -> Option<Node<'a, 'input>> {
+    node.children()
+        .find(|n| n.is_element() && n.tag_name().name() == local_name)
+}
+
+fn is_tag(node: &Node, local_name: &str) -> bool {
+    node.is_element() && node.tag_name().name() == local_name
+}
+
+fn attr_f64(node: Node, name: &str) -> f64 {
+    node.attribute(name)
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0.0)
+}
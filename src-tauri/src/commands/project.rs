@@ -27,7 +27,7 @@ pub struct ImageRename {
 }
 
 /// Validate that a path stays within the given base directory (prevent path traversal).
-fn validate_path_within(base: &std::path::Path, relative: &str) -> Result<PathBuf, String> {
+pub fn validate_path_within(base: &std::path::Path, relative: &str) -> Result<PathBuf, String> {
     let joined = base.join(relative);
     // Normalize components to resolve .. and .
     let mut normalized = PathBuf::new();
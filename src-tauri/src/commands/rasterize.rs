@@ -0,0 +1,721 @@
+//! Page rasterization backends used by `convert_internal`.
+//! Each backend turns a PDF into RGBA page images; saving, hotspots and
+//! progress reporting are handled by the caller so they behave the same
+//! regardless of which backend rendered the pages.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use image::RgbaImage;
+use pdfium_render::prelude::*;
+use tauri::Manager;
+
+use crate::commands::settings::RasterizerBackend;
+use crate::models::{TextLayer, TextWord};
+
+/// Where a link on a page points to.
+#[derive(Clone)]
+pub enum LinkTarget {
+    Url(String),
+    /// Zero-based page index within the same document.
+    Page(usize),
+}
+
+pub struct PageLink {
+    /// Bounding box as percentages of the page size: (x, y, w, h).
+    pub bounds: (f64, f64, f64, f64),
+    pub target: LinkTarget,
+}
+
+pub struct RenderOptions {
+    pub dpi: f32,
+    /// Fixed output width in pixels; overrides `dpi` when set.
+    pub target_width: Option<u32>,
+    /// Pages to render; all pages when `None`.
+    pub pages: Option<PageSelection>,
+}
+
+impl RenderOptions {
+    /// Zero-based indices of the pages to render, in ascending order.
+    pub fn selected_pages(&self, total_pages: usize) -> Result<Vec<usize>, String> {
+        match &self.pages {
+            Some(selection) => selection.resolve(total_pages),
+            None => Ok((0..total_pages).collect()),
+        }
+    }
+}
+
+/// A page selection such as `"1,3,7-9"` or `"5-"`, using 1-based page numbers.
+#[derive(Debug, Clone)]
+pub struct PageSelection {
+    /// Inclusive ranges; an open end means "to the last page".
+    ranges: Vec<(usize, Option<usize>)>,
+}
+
+impl PageSelection {
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut ranges = Vec::new();
+        for part in spec.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let parse_page = |s: &str| -> Result<usize, String> {
+                match s.trim().parse::<usize>() {
+                    Ok(page) if page > 0 => Ok(page),
+                    _ => Err(format!("ページ指定が不正です: {}", part)),
+                }
+            };
+            let range = match part.split_once('-') {
+                Some((start, end)) => {
+                    let start = parse_page(start)?;
+                    let end = if end.trim().is_empty() {
+                        None
+                    } else {
+                        Some(parse_page(end)?)
+                    };
+                    if end.is_some_and(|end| end < start) {
+                        return Err(format!("ページ指定が不正です: {}", part));
+                    }
+                    (start, end)
+                }
+                None => {
+                    let page = parse_page(part)?;
+                    (page, Some(page))
+                }
+            };
+            ranges.push(range);
+        }
+        if ranges.is_empty() {
+            return Err("ページが指定されていません".to_string());
+        }
+        Ok(PageSelection { ranges })
+    }
+
+    /// Select exactly the given zero-based page indices.
+    pub fn from_indices(indices: &[usize]) -> Self {
+        PageSelection {
+            ranges: indices.iter().map(|&i| (i + 1, Some(i + 1))).collect(),
+        }
+    }
+
+    /// Zero-based indices of the selected pages, sorted and without duplicates.
+    pub fn resolve(&self, total_pages: usize) -> Result<Vec<usize>, String> {
+        let mut pages = Vec::new();
+        for &(start, end) in &self.ranges {
+            let end = end.unwrap_or(total_pages);
+            if start > total_pages || end > total_pages {
+                return Err(format!(
+                    "ページ {} は存在しません (全 {} ページ)",
+                    end.max(start),
+                    total_pages
+                ));
+            }
+            pages.extend(start - 1..end);
+        }
+        pages.sort_unstable();
+        pages.dedup();
+        Ok(pages)
+    }
+}
+
+pub struct RenderedPage {
+    /// Zero-based page index within the document.
+    pub index: usize,
+    pub image: RgbaImage,
+    /// Link annotations, for backends that can read them.
+    pub links: Vec<PageLink>,
+    /// Text on the page; `None` when the page has no text or it could not be read.
+    pub text: Option<TextLayer>,
+}
+
+/// Document-level information reported before the first page is rendered.
+pub struct DocumentInfo {
+    /// Zero-based indices of the pages that will be rendered.
+    pub pages: Vec<usize>,
+    /// Bookmark tree, for backends that can read it.
+    pub outline: Vec<OutlineEntry>,
+}
+
+/// A bookmark pointing at a page of the document.
+pub struct OutlineEntry {
+    pub title: String,
+    /// Zero-based page index.
+    pub page: usize,
+    pub children: Vec<OutlineEntry>,
+}
+
+pub trait Rasterizer {
+    fn name(&self) -> &'static str;
+
+    /// Render the selected pages in order. `on_start` receives the document's
+    /// page selection and outline before the first page is rendered;
+    /// `on_page` is called as each page is finished.
+    fn rasterize(
+        &self,
+        pdf_path: &str,
+        options: &RenderOptions,
+        on_start: &mut dyn FnMut(DocumentInfo),
+        on_page: &mut dyn FnMut(RenderedPage) -> Result<(), String>,
+    ) -> Result<(), String>;
+}
+
+/// Build the list of backends to try, in order, for the given preference.
+/// `Auto` returns every backend that is installed, pdfium first.
+pub fn available_rasterizers(
+    app: &tauri::AppHandle,
+    preference: RasterizerBackend,
+) -> Result<Vec<Box<dyn Rasterizer>>, String> {
+    let pdfium = || -> Result<Box<dyn Rasterizer>, String> {
+        let library_path = resolve_pdfium_path(app)?;
+        Ok(Box::new(PdfiumRasterizer { library_path }))
+    };
+    let poppler = || -> Result<Box<dyn Rasterizer>, String> { Ok(Box::new(find_poppler()?)) };
+
+    match preference {
+        RasterizerBackend::Pdfium => Ok(vec![pdfium()?]),
+        RasterizerBackend::Poppler => Ok(vec![poppler()?]),
+        RasterizerBackend::Auto => {
+            let mut backends: Vec<Box<dyn Rasterizer>> = Vec::new();
+            let mut errors: Vec<String> = Vec::new();
+            for backend in [pdfium(), poppler()] {
+                match backend {
+                    Ok(backend) => backends.push(backend),
+                    Err(e) => errors.push(e),
+                }
+            }
+            if backends.is_empty() {
+                return Err(format!(
+                    "利用可能なレンダリングエンジンがありません。\n\n{}",
+                    errors.join("\n\n")
+                ));
+            }
+            Ok(backends)
+        }
+    }
+}
+
+pub struct PdfiumRasterizer {
+    library_path: PathBuf,
+}
+
+impl Rasterizer for PdfiumRasterizer {
+    fn name(&self) -> &'static str {
+        "PDFium"
+    }
+
+    fn rasterize(
+        &self,
+        pdf_path: &str,
+        options: &RenderOptions,
+        on_start: &mut dyn FnMut(DocumentInfo),
+        on_page: &mut dyn FnMut(RenderedPage) -> Result<(), String>,
+    ) -> Result<(), String> {
+        let pdfium = Pdfium::new(
+            Pdfium::bind_to_library(self.library_path.to_str().unwrap_or_default())
+                .map_err(|e| format!("PDFium ライブラリの読み込みに失敗: {}", e))?,
+        );
+
+        let document = pdfium
+            .load_pdf_from_file(pdf_path, None)
+            .map_err(|e| format!("PDF ファイルを開けません: {}", e))?;
+
+        let total_pages = document.pages().len() as usize;
+        let selected = options.selected_pages(total_pages)?;
+        on_start(DocumentInfo {
+            pages: selected.clone(),
+            outline: read_outline(document.bookmarks().root(), total_pages),
+        });
+
+        for &index in &selected {
+            let page = document
+                .pages()
+                .get(index as PdfPageIndex)
+                .map_err(|e| format!("ページ {} の読み込みに失敗: {}", index + 1, e))?;
+
+            // Sized per page so mixed portrait/landscape decks keep their proportions
+            let render_config = match options.target_width {
+                Some(width) => PdfRenderConfig::new().set_target_width(width as i32),
+                None => PdfRenderConfig::new()
+                    .set_target_width((options.dpi / 72.0 * page.width().value) as i32)
+                    .set_maximum_height((options.dpi / 72.0 * page.height().value) as i32),
+            };
+
+            let bitmap = page
+                .render_with_config(&render_config)
+                .map_err(|e| format!("ページ {} のレンダリングに失敗: {}", index + 1, e))?;
+
+            on_page(RenderedPage {
+                index,
+                image: bitmap.as_image().into_rgba8(),
+                links: extract_page_links(&page, total_pages),
+                text: extract_page_text(&page),
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Convert a PDF rectangle (origin bottom-left, in points) into the
+/// top-left based percentage coordinates used by the editor.
+fn rect_to_percent(rect: &PdfRect, page_width: f32, page_height: f32) -> (f64, f64, f64, f64) {
+    let left = (rect.left().value / page_width).clamp(0.0, 1.0) as f64;
+    let right = (rect.right().value / page_width).clamp(0.0, 1.0) as f64;
+    let top = ((page_height - rect.top().value) / page_height).clamp(0.0, 1.0) as f64;
+    let bottom = ((page_height - rect.bottom().value) / page_height).clamp(0.0, 1.0) as f64;
+    (
+        left.min(right) * 100.0,
+        top.min(bottom) * 100.0,
+        (right - left).abs() * 100.0,
+        (bottom - top).abs() * 100.0,
+    )
+}
+
+fn link_target(link: &PdfLink, total_pages: usize) -> Option<LinkTarget> {
+    let page_index = match link.action() {
+        Some(PdfAction::Uri(action)) => {
+            let url = action.uri().ok()?;
+            return (!url.trim().is_empty()).then_some(LinkTarget::Url(url));
+        }
+        Some(PdfAction::LocalDestination(action)) => action.destination().ok()?.page_index().ok()?,
+        Some(_) => return None,
+        // Links without an action carry their destination directly (/Dest)
+        None => link.destination()?.page_index().ok()?,
+    } as usize;
+    (page_index < total_pages).then_some(LinkTarget::Page(page_index))
+}
+
+/// Read a bookmark and its following siblings, recursing into children.
+/// Bookmarks that do not point at a page of this document are skipped, but their
+/// children are kept at the same level.
+fn read_outline(first: Option<PdfBookmark>, total_pages: usize) -> Vec<OutlineEntry> {
+    let mut entries = Vec::new();
+    let mut next = first;
+    while let Some(bookmark) = next {
+        let children = read_outline(bookmark.first_child(), total_pages);
+        let page = match bookmark.action() {
+            Some(PdfAction::LocalDestination(action)) => {
+                action.destination().ok().and_then(|dest| dest.page_index().ok())
+            }
+            Some(_) => None,
+            None => bookmark.destination().and_then(|dest| dest.page_index().ok()),
+        }
+        .map(|page| page as usize)
+        .filter(|page| *page < total_pages);
+
+        match page {
+            Some(page) => entries.push(OutlineEntry {
+                title: bookmark.title().unwrap_or_default().trim().to_string(),
+                page,
+                children,
+            }),
+            None => entries.extend(children),
+        }
+        next = bookmark.next_sibling();
+    }
+    entries
+}
+
+/// Read the page's link annotations: URI actions and GoTo actions within the document.
+fn extract_page_links(page: &PdfPage, total_pages: usize) -> Vec<PageLink> {
+    let page_width = page.width().value;
+    let page_height = page.height().value;
+    if page_width <= 0.0 || page_height <= 0.0 {
+        return Vec::new();
+    }
+
+    page.links()
+        .iter()
+        .filter_map(|link| {
+            let target = link_target(&link, total_pages)?;
+            let rect = link.rect().ok()?;
+            let bounds = rect_to_percent(&rect, page_width, page_height);
+            if bounds.2 <= 0.0 || bounds.3 <= 0.0 {
+                return None;
+            }
+            Some(PageLink { bounds, target })
+        })
+        .collect()
+}
+
+/// Read the page's text, splitting it into words at whitespace.
+fn extract_page_text(page: &PdfPage) -> Option<TextLayer> {
+    let page_width = page.width().value;
+    let page_height = page.height().value;
+    if page_width <= 0.0 || page_height <= 0.0 {
+        return None;
+    }
+
+    let text = page.text().ok()?;
+    let full_text = text.all().trim().to_string();
+    if full_text.is_empty() {
+        return None;
+    }
+
+    let mut words: Vec<TextWord> = Vec::new();
+    let mut current: Option<PendingWord> = None;
+    for ch in text.chars().iter() {
+        let Some(c) = ch.unicode_char() else { continue };
+        if c.is_whitespace() || c.is_control() {
+            if let Some(word) = current.take() {
+                words.push(word.finish(page_width, page_height));
+            }
+            continue;
+        }
+        let Ok(bounds) = ch.tight_bounds() else { continue };
+        let font_size = ch.scaled_font_size().value;
+        match current.as_mut() {
+            Some(word) => word.push(c, &bounds, font_size),
+            None => {
+                current = Some(PendingWord {
+                    text: c.to_string(),
+                    bounds,
+                    font_size,
+                })
+            }
+        }
+    }
+    if let Some(word) = current {
+        words.push(word.finish(page_width, page_height));
+    }
+
+    Some(TextLayer {
+        text: full_text,
+        words,
+    })
+}
+
+/// A word being assembled from consecutive characters.
+struct PendingWord {
+    text: String,
+    /// Union of the characters' bounds, in page points.
+    bounds: PdfRect,
+    font_size: f32,
+}
+
+impl PendingWord {
+    fn push(&mut self, c: char, bounds: &PdfRect, font_size: f32) {
+        self.text.push(c);
+        self.bounds = PdfRect::new_from_values(
+            self.bounds.bottom().value.min(bounds.bottom().value),
+            self.bounds.left().value.min(bounds.left().value),
+            self.bounds.top().value.max(bounds.top().value),
+            self.bounds.right().value.max(bounds.right().value),
+        );
+        self.font_size = self.font_size.max(font_size);
+    }
+
+    fn finish(self, page_width: f32, page_height: f32) -> TextWord {
+        let (x, y, w, h) = rect_to_percent(&self.bounds, page_width, page_height);
+        TextWord {
+            text: self.text,
+            x,
+            y,
+            w,
+            h,
+            font_size: self.font_size as f64,
+        }
+    }
+}
+
+/// Renders through poppler-utils' `pdftoppm`, one subprocess per page.
+pub struct PopplerRasterizer {
+    pdftoppm: PathBuf,
+    pdfinfo: PathBuf,
+    /// Optional; pages are imported without text when it is missing.
+    pdftotext: Option<PathBuf>,
+}
+
+impl PopplerRasterizer {
+    fn page_count(&self, pdf_path: &str) -> Result<usize, String> {
+        let output = Command::new(&self.pdfinfo)
+            .arg(pdf_path)
+            .output()
+            .map_err(|e| format!("pdfinfo の起動に失敗: {}", e))?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("PDF ファイルを開けません: {}", stderr.trim()));
+        }
+
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .find_map(|line| line.strip_prefix("Pages:"))
+            .and_then(|count| count.trim().parse().ok())
+            .ok_or_else(|| "PDF のページ数を取得できません".to_string())
+    }
+
+    fn render_page(
+        &self,
+        pdf_path: &str,
+        index: usize,
+        options: &RenderOptions,
+        work_dir: &Path,
+    ) -> Result<RgbaImage, String> {
+        let page_number = (index + 1).to_string();
+        let prefix = work_dir.join("page");
+        let mut command = Command::new(&self.pdftoppm);
+        command.args(["-f", &page_number, "-l", &page_number]);
+        match options.target_width {
+            Some(width) => command.args(["-scale-to-x", &width.to_string(), "-scale-to-y", "-1"]),
+            None => command.args(["-r", &options.dpi.to_string()]),
+        };
+        let output = command
+            .args(["-png", "-singlefile"])
+            .arg(pdf_path)
+            .arg(&prefix)
+            .output()
+            .map_err(|e| format!("pdftoppm の起動に失敗: {}", e))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!(
+                "ページ {} のレンダリングに失敗: {}",
+                index + 1,
+                stderr.trim()
+            ));
+        }
+
+        let png_path = prefix.with_extension("png");
+        let image = image::open(&png_path)
+            .map_err(|e| format!("ページ {} の画像読み込みに失敗: {}", index + 1, e))?
+            .into_rgba8();
+        let _ = std::fs::remove_file(&png_path);
+        Ok(image)
+    }
+
+    /// Read a page's words through `pdftotext -bbox`.
+    fn extract_text(&self, pdf_path: &str, index: usize) -> Option<TextLayer> {
+        let pdftotext = self.pdftotext.as_ref()?;
+        let page_number = (index + 1).to_string();
+        let output = Command::new(pdftotext)
+            .args(["-f", &page_number, "-l", &page_number, "-bbox", "-enc", "UTF-8"])
+            .arg(pdf_path)
+            .arg("-")
+            .output()
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        parse_bbox_html(&String::from_utf8_lossy(&output.stdout))
+    }
+}
+
+/// Parse the XHTML written by `pdftotext -bbox`: a `<page width height>` element
+/// followed by `<word xMin yMin xMax yMax>` elements in points, origin top-left.
+fn parse_bbox_html(html: &str) -> Option<TextLayer> {
+    fn attr(tag: &str, name: &str) -> Option<f64> {
+        let start = tag.find(&format!("{}=\"", name))? + name.len() + 2;
+        let end = start + tag[start..].find('"')?;
+        tag[start..end].parse().ok()
+    }
+    fn unescape(text: &str) -> String {
+        text.replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&apos;", "'")
+            .replace("&amp;", "&")
+    }
+
+    let page_start = html.find("<page ")?;
+    let page_tag = &html[page_start..page_start + html[page_start..].find('>')?];
+    let page_width = attr(page_tag, "width").filter(|w| *w > 0.0)?;
+    let page_height = attr(page_tag, "height").filter(|h| *h > 0.0)?;
+
+    let mut words: Vec<TextWord> = Vec::new();
+    for chunk in html[page_start..].split("<word ").skip(1) {
+        let Some(tag_end) = chunk.find('>') else { continue };
+        let Some(text_end) = chunk.find("</word>") else { continue };
+        let tag = &chunk[..tag_end];
+        let (Some(x_min), Some(y_min), Some(x_max), Some(y_max)) = (
+            attr(tag, "xMin"),
+            attr(tag, "yMin"),
+            attr(tag, "xMax"),
+            attr(tag, "yMax"),
+        ) else {
+            continue;
+        };
+        let text = unescape(chunk[tag_end + 1..text_end].trim());
+        if text.is_empty() {
+            continue;
+        }
+        words.push(TextWord {
+            text,
+            x: (x_min / page_width * 100.0).clamp(0.0, 100.0),
+            y: (y_min / page_height * 100.0).clamp(0.0, 100.0),
+            w: ((x_max - x_min) / page_width * 100.0).clamp(0.0, 100.0),
+            h: ((y_max - y_min) / page_height * 100.0).clamp(0.0, 100.0),
+            // pdftotext does not report font sizes; the word box height is close enough
+            font_size: y_max - y_min,
+        });
+    }
+
+    if words.is_empty() {
+        return None;
+    }
+    let text = words
+        .iter()
+        .map(|word| word.text.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+    Some(TextLayer { text, words })
+}
+
+impl Rasterizer for PopplerRasterizer {
+    fn name(&self) -> &'static str {
+        "Poppler"
+    }
+
+    fn rasterize(
+        &self,
+        pdf_path: &str,
+        options: &RenderOptions,
+        on_start: &mut dyn FnMut(DocumentInfo),
+        on_page: &mut dyn FnMut(RenderedPage) -> Result<(), String>,
+    ) -> Result<(), String> {
+        let total_pages = self.page_count(pdf_path)?;
+        let selected = options.selected_pages(total_pages)?;
+        on_start(DocumentInfo {
+            pages: selected.clone(),
+            outline: Vec::new(),
+        });
+
+        let work_dir = std::env::temp_dir().join(format!("slide-linker-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&work_dir)
+            .map_err(|e| format!("一時ディレクトリの作成に失敗: {}", e))?;
+
+        let result = selected.iter().try_for_each(|&index| {
+            let image = self.render_page(pdf_path, index, options, &work_dir)?;
+            on_page(RenderedPage {
+                index,
+                image,
+                links: Vec::new(),
+                text: self.extract_text(pdf_path, index),
+            })
+        });
+
+        let _ = std::fs::remove_dir_all(&work_dir);
+        result
+    }
+}
+
+#[cfg(windows)]
+const POPPLER_EXE_SUFFIX: &str = ".exe";
+#[cfg(not(windows))]
+const POPPLER_EXE_SUFFIX: &str = "";
+
+/// Platform-specific directories where poppler-utils are commonly installed.
+fn poppler_install_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+
+    // Explicit override, e.g. the `Library\bin` folder of a poppler-windows release
+    if let Some(custom) = std::env::var_os("POPPLER_PATH") {
+        dirs.push(PathBuf::from(custom));
+    }
+
+    // Bundled next to the executable
+    if let Ok(exe) = std::env::current_exe() {
+        if let Some(exe_dir) = exe.parent() {
+            dirs.push(exe_dir.join("poppler").join("Library").join("bin"));
+            dirs.push(exe_dir.join("poppler").join("bin"));
+        }
+    }
+
+    #[cfg(windows)]
+    dirs.push(PathBuf::from(r"C:\Program Files\poppler\Library\bin"));
+
+    #[cfg(not(windows))]
+    dirs.extend(
+        ["/usr/bin", "/usr/local/bin", "/opt/homebrew/bin"]
+            .iter()
+            .map(PathBuf::from),
+    );
+
+    if let Some(path_var) = std::env::var_os("PATH") {
+        dirs.extend(std::env::split_paths(&path_var));
+    }
+
+    dirs
+}
+
+fn find_poppler() -> Result<PopplerRasterizer, String> {
+    let pdftoppm_name = format!("pdftoppm{}", POPPLER_EXE_SUFFIX);
+    let pdfinfo_name = format!("pdfinfo{}", POPPLER_EXE_SUFFIX);
+    let mut searched_paths: Vec<String> = Vec::new();
+
+    for dir in poppler_install_dirs() {
+        let pdftoppm = dir.join(&pdftoppm_name);
+        let pdfinfo = dir.join(&pdfinfo_name);
+        if pdftoppm.is_file() && pdfinfo.is_file() {
+            let pdftotext = dir.join(format!("pdftotext{}", POPPLER_EXE_SUFFIX));
+            return Ok(PopplerRasterizer {
+                pdftoppm,
+                pdfinfo,
+                pdftotext: pdftotext.is_file().then_some(pdftotext),
+            });
+        }
+        searched_paths.push(format!("poppler: {}", dir.display()));
+    }
+
+    Err(format!(
+        "Poppler ({} / {}) が見つかりません。\n\
+         検索パス:\n{}",
+        pdftoppm_name,
+        pdfinfo_name,
+        searched_paths.join("\n")
+    ))
+}
+
+fn resolve_pdfium_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    let lib_name = Pdfium::pdfium_platform_library_name();
+    let mut searched_paths: Vec<String> = Vec::new();
+
+    // 1. Tauri resource_dir (production NSIS/MSI/deb/AppImage build)
+    if let Ok(resource_dir) = app.path().resource_dir() {
+        let path = resource_dir.join(&lib_name);
+        searched_paths.push(format!("resource_dir: {}", path.display()));
+        if path.exists() {
+            return Ok(path);
+        }
+    }
+
+    // 2. Next to the executable
+    if let Ok(exe_path) = std::env::current_exe() {
+        if let Some(exe_dir) = exe_path.parent() {
+            let path = exe_dir.join(&lib_name);
+            searched_paths.push(format!("exe_dir: {}", path.display()));
+            if path.exists() {
+                return Ok(path);
+            }
+        }
+    }
+
+    // 3. Development: src-tauri/resources/<library>
+    let dev_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("resources")
+        .join(&lib_name);
+    searched_paths.push(format!("dev: {}", dev_path.display()));
+    if dev_path.exists() {
+        return Ok(dev_path);
+    }
+
+    // 4. System library directories
+    #[cfg(not(windows))]
+    for dir in [
+        "/usr/local/lib",
+        "/usr/lib",
+        "/usr/lib64",
+        "/usr/lib/x86_64-linux-gnu",
+        "/usr/lib/aarch64-linux-gnu",
+        "/opt/homebrew/lib",
+    ] {
+        let path = PathBuf::from(dir).join(&lib_name);
+        searched_paths.push(format!("system: {}", path.display()));
+        if path.exists() {
+            return Ok(path);
+        }
+    }
+
+    Err(format!(
+        "{} が見つかりません。\n\
+         検索パス:\n{}",
+        lib_name.to_string_lossy(),
+        searched_paths.join("\n")
+    ))
+}
@@ -4,6 +4,22 @@ use std::path::PathBuf;
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct AppSettings {
     pub recent_projects: Vec<RecentProjectEntry>,
+    #[serde(default)]
+    pub rasterizer: RasterizerBackend,
+    /// Seconds a LibreOffice conversion may run before it is killed.
+    #[serde(default)]
+    pub libreoffice_timeout_secs: Option<u64>,
+}
+
+/// Which engine renders PDF pages to images. `Auto` tries PDFium first and
+/// falls back to Poppler when PDFium is missing or fails to load.
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RasterizerBackend {
+    #[default]
+    Auto,
+    Pdfium,
+    Poppler,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -20,6 +36,15 @@ fn settings_path() -> PathBuf {
     path
 }
 
+/// Synchronous read for use inside blocking commands. Falls back to defaults
+/// when the file is missing or unreadable.
+pub fn read_settings() -> AppSettings {
+    std::fs::read_to_string(settings_path())
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
 #[tauri::command]
 pub async fn load_settings() -> Result<AppSettings, String> {
     let path = settings_path();
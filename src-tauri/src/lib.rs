@@ -9,6 +9,7 @@ pub fn run() {
             commands::convert::convert_pdf_to_images,
             commands::convert::convert_to_images_with_offset,
             commands::convert::detect_aspect_ratio_from_slides,
+            commands::convert::rerender_project,
             commands::project::save_project,
             commands::project::load_project,
             commands::project::copy_slide_images,
@@ -17,6 +18,7 @@ pub fn run() {
             commands::export::export_html,
             commands::export_pdf::export_pdf,
             commands::image::read_image_base64,
+            commands::image_sequence::import_image_sequence,
             commands::settings::load_settings,
             commands::settings::save_settings,
         ])
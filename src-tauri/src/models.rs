@@ -12,6 +12,12 @@ pub struct Project {
     pub slides: Vec<Slide>,
     #[serde(default)]
     pub enable_analytics: Option<bool>,
+    /// Resolution slides are rendered at. `render_width` (pixels) takes
+    /// precedence over `render_dpi` when both are set.
+    #[serde(default)]
+    pub render_dpi: Option<f32>,
+    #[serde(default)]
+    pub render_width: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -30,6 +36,54 @@ pub struct Slide {
     pub graph_links: Vec<String>,
     #[serde(default)]
     pub source_file: Option<String>,
+    /// Zero-based page of `source_file` this slide was rendered from.
+    #[serde(default)]
+    pub source_page: Option<usize>,
+    /// Rendered image size in pixels; decks may mix page sizes and orientations.
+    #[serde(default)]
+    pub width: Option<u32>,
+    #[serde(default)]
+    pub height: Option<u32>,
+    #[serde(default)]
+    pub aspect_ratio: Option<String>,
+    /// Text extracted from the source page, if it had any.
+    #[serde(default)]
+    pub text_layer: Option<TextLayer>,
+    /// Speaker notes imported from the source deck.
+    #[serde(default)]
+    pub notes: Option<String>,
+    /// Section of the source deck the slide belongs to.
+    #[serde(default)]
+    pub section: Option<String>,
+}
+
+impl Slide {
+    /// CSS `aspect-ratio` value for this slide, falling back to the project's ratio
+    /// for slides imported before per-slide sizes were recorded.
+    pub fn aspect_ratio_css(&self, project_ratio: &str) -> String {
+        match (self.width, self.height) {
+            (Some(w), Some(h)) if w > 0 && h > 0 => format!("{}/{}", w, h),
+            _ => project_ratio.replace(':', "/"),
+        }
+    }
+
+    /// Width divided by height, with the same fallback as `aspect_ratio_css`.
+    pub fn aspect_ratio_value(&self, project_ratio: &str) -> f64 {
+        match (self.width, self.height) {
+            (Some(w), Some(h)) if w > 0 && h > 0 => w as f64 / h as f64,
+            _ => parse_aspect_ratio(project_ratio)
+                .map(|(w, h)| w / h)
+                .unwrap_or(16.0 / 9.0),
+        }
+    }
+}
+
+/// Parse a `"W:H"` ratio string. Returns `None` for malformed or non-positive values.
+pub fn parse_aspect_ratio(ratio: &str) -> Option<(f64, f64)> {
+    let (w, h) = ratio.split_once(':')?;
+    let w: f64 = w.trim().parse().ok()?;
+    let h: f64 = h.trim().parse().ok()?;
+    (w > 0.0 && h > 0.0).then_some((w, h))
 }
 
 fn default_true() -> bool {
@@ -52,6 +106,27 @@ pub struct TextOverlay {
     pub border_radius: f64,
 }
 
+/// Text found on a source page, in reading order.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TextLayer {
+    pub text: String,
+    #[serde(default)]
+    pub words: Vec<TextWord>,
+}
+
+/// A single word; position and size are percentages of the page like hotspots.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TextWord {
+    pub text: String,
+    pub x: f64,
+    pub y: f64,
+    pub w: f64,
+    pub h: f64,
+    /// Font size in points.
+    #[serde(default)]
+    pub font_size: f64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HotspotStyle {
     pub color: String,
@@ -83,6 +158,36 @@ pub struct SlideInfo {
     pub index: usize,
     pub label: String,
     pub image_path: String,
+    #[serde(default)]
+    pub hotspots: Vec<Hotspot>,
+    pub source_page: usize,
+    pub width: u32,
+    pub height: u32,
+    pub aspect_ratio: String,
+    #[serde(default)]
+    pub text_layer: Option<TextLayer>,
+    #[serde(default)]
+    pub notes: Option<String>,
+    /// Proposed values; the first slide of each source section is main, hidden slides are disabled.
+    pub is_main: bool,
+    pub enabled: bool,
+    #[serde(default)]
+    pub section: Option<String>,
+    /// Proposed graph links, e.g. from a parent bookmark's page to its children.
+    #[serde(default)]
+    pub graph_links: Vec<String>,
+}
+
+/// Optional settings accepted by the conversion commands.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ConvertOptions {
+    #[serde(default)]
+    pub render_dpi: Option<f32>,
+    #[serde(default)]
+    pub render_width: Option<u32>,
+    /// Pages to import, e.g. `"1,3,7-9"` (1-based). All pages when empty.
+    #[serde(default)]
+    pub pages: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
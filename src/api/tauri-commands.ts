@@ -1,30 +1,76 @@
 import { invoke } from '@tauri-apps/api/core'
-import type { Project } from '../types'
+import type { Hotspot, Project, TextLayer } from '../types'
 
 export interface SlideInfo {
   id: string
   index: number
   label: string
   image_path: string
+  hotspots: Hotspot[]
+  source_page: number
+  width: number
+  height: number
+  aspect_ratio: string
+  text_layer?: TextLayer
+  notes?: string
+  /** Proposed: first slide of each source section is main */
+  is_main: boolean
+  /** Proposed: hidden source slides are disabled */
+  enabled: boolean
+  section?: string
+  /** Proposed graph links, e.g. from a bookmark's page to its child bookmarks */
+  graph_links?: string[]
+}
+
+export interface ConvertOptions {
+  render_dpi?: number
+  render_width?: number
+  /** 1-based page selection such as "1,3,7-9"; all pages when omitted. */
+  pages?: string
 }
 
 export const tauriCommands = {
-  convertPdfToImages(pdfPath: string, outputDir: string): Promise<SlideInfo[]> {
-    return invoke('convert_pdf_to_images', { pdfPath, outputDir })
+  convertPdfToImages(
+    pdfPath: string,
+    outputDir: string,
+    options?: ConvertOptions,
+  ): Promise<SlideInfo[]> {
+    return invoke('convert_pdf_to_images', { pdfPath, outputDir, options })
   },
 
   convertToImagesWithOffset(
     sourcePath: string,
     outputDir: string,
     startIndex: number,
+    options?: ConvertOptions,
   ): Promise<SlideInfo[]> {
     return invoke('convert_to_images_with_offset', {
       sourcePath,
       outputDir,
       startIndex,
+      options,
     })
   },
 
+  /** Import a folder or .zip of PNG/JPEG images as slides */
+  importImageSequence(
+    sourcePath: string,
+    outputDir: string,
+    startIndex: number,
+    options?: ConvertOptions,
+  ): Promise<SlideInfo[]> {
+    return invoke('import_image_sequence', {
+      sourcePath,
+      outputDir,
+      startIndex,
+      options,
+    })
+  },
+
+  rerenderProject(projectDir: string, project: Project): Promise<Project> {
+    return invoke('rerender_project', { projectDir, project })
+  },
+
   detectAspectRatio(slidesDir: string): Promise<string> {
     return invoke('detect_aspect_ratio_from_slides', { slidesDir })
   },
@@ -91,6 +137,9 @@ export interface RecentProjectEntry {
   opened_at: string
 }
 
+export type RasterizerBackend = 'auto' | 'pdfium' | 'poppler'
+
 export interface AppSettings {
   recent_projects: RecentProjectEntry[]
+  rasterizer?: RasterizerBackend
 }
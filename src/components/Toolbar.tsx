@@ -25,6 +25,8 @@ export function Toolbar() {
     exportHtml,
     exportPdf,
     importAdditionalSlides,
+    importImageFolder,
+    rerenderSlides,
     closeProject,
   } = useProjectActions()
 
@@ -135,6 +137,20 @@ export function Toolbar() {
         >
           追加
         </button>
+        <button
+          className="toolbar-btn"
+          onClick={importImageFolder}
+          title="画像フォルダからスライドを追加"
+        >
+          画像
+        </button>
+        <button
+          className="toolbar-btn"
+          onClick={rerenderSlides}
+          title="解像度を変更して再レンダリング"
+        >
+          解像度
+        </button>
         <label
           className="toolbar-analytics-toggle"
           title="Export時に閲覧分析トラッキングを埋め込む"
@@ -138,7 +138,9 @@ export function EditorCanvas() {
       tabIndex={0}
     >
       <div className="editor-canvas-viewport" style={{ maxWidth: `${zoomLevel * 10}px` }}>
-        <SlideWrapper aspectRatio={project?.aspect_ratio ?? '16:9'}>
+        <SlideWrapper
+          aspectRatio={currentSlide.aspect_ratio ?? project?.aspect_ratio ?? '16:9'}
+        >
           {imageSrc && (
             <img
               src={imageSrc}
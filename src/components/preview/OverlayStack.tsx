@@ -49,7 +49,7 @@ export function OverlayStack({ slides, aspectRatio }: OverlayStackProps) {
               &#8592; 戻る
             </button>
             <div className="overlay-content">
-              <SlideWrapper aspectRatio={aspectRatio}>
+              <SlideWrapper aspectRatio={slide.aspect_ratio ?? aspectRatio}>
                 {imageSrc && (
                   <img
                     src={imageSrc}
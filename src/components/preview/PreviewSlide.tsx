@@ -33,7 +33,7 @@ export function PreviewSlide({ slide, aspectRatio }: PreviewSlideProps) {
 
   return (
     <div className="preview-slide" data-slide-id={slide.id}>
-      <SlideWrapper aspectRatio={aspectRatio}>
+      <SlideWrapper aspectRatio={slide.aspect_ratio ?? aspectRatio}>
         {imageSrc && (
           <img
             src={imageSrc}
@@ -1,6 +1,6 @@
 import { open, save } from '@tauri-apps/plugin-dialog'
 import { useStore } from '../store'
-import { tauriCommands } from '../api/tauri-commands'
+import { tauriCommands, type ConvertOptions } from '../api/tauri-commands'
 import type { Project, Slide, AspectRatio } from '../types'
 
 /** Normalize a directory path for comparison (Windows-safe). */
@@ -8,6 +8,56 @@ function normalizeDir(dir: string): string {
   return dir.replace(/\\/g, '/').replace(/\/+$/, '').toLowerCase()
 }
 
+/**
+ * Ask which pages to import. Returns `undefined` for all pages,
+ * or `null` when the user cancels.
+ */
+function promptPageSelection(): string | undefined | null {
+  const input = window.prompt(
+    '取り込むページを指定してください（例: 1,3,7-9）。\n' +
+      '空欄のままにするとすべてのページを取り込みます。',
+    '',
+  )
+  if (input === null) return null
+  return input.trim() || undefined
+}
+
+/** The backend detects formats by content; this only narrows the file dialog. */
+const PRESENTATION_EXTENSIONS = [
+  'pdf',
+  'pptx',
+  'ppsx',
+  'potx',
+  'ppt',
+  'pps',
+  'odp',
+  'key',
+]
+
+/** Folders and .zip archives are imported as image sequences. */
+function convertSource(
+  sourcePath: string,
+  slidesDir: string,
+  startIndex: number,
+  options: ConvertOptions,
+  isFolder = false,
+) {
+  if (isFolder || /\.zip$/i.test(sourcePath)) {
+    return tauriCommands.importImageSequence(
+      sourcePath,
+      slidesDir,
+      startIndex,
+      options,
+    )
+  }
+  return tauriCommands.convertToImagesWithOffset(
+    sourcePath,
+    slidesDir,
+    startIndex,
+    options,
+  )
+}
+
 export function useProjectActions() {
   const setProject = useStore((s) => s.setProject)
   const setProjectPath = useStore((s) => s.setProjectPath)
@@ -49,9 +99,11 @@ export function useProjectActions() {
     const sourcePath = await open({
       title: 'Select presentation file',
       filters: [
-        { name: 'Presentation (PDF / PPTX)', extensions: ['pdf', 'pptx'] },
+        { name: 'Presentation', extensions: PRESENTATION_EXTENSIONS },
         { name: 'PDF', extensions: ['pdf'] },
-        { name: 'PowerPoint', extensions: ['pptx'] },
+        { name: 'PowerPoint', extensions: ['pptx', 'ppsx', 'potx', 'ppt', 'pps'] },
+        { name: 'OpenDocument / Keynote', extensions: ['odp', 'key'] },
+        { name: 'Images (ZIP)', extensions: ['zip'] },
       ],
     })
     if (!sourcePath) return
@@ -83,9 +135,12 @@ export function useProjectActions() {
       // Directory doesn't exist yet — safe to proceed
     }
 
+    const pages = promptPageSelection()
+    if (pages === null) return
+
     setLoading(true, 'スライド画像を変換中...')
     try {
-      const slides = await tauriCommands.convertPdfToImages(sourcePath, slidesDir)
+      const slides = await convertSource(sourcePath, slidesDir, 0, { pages })
       const aspectRatio = await tauriCommands.detectAspectRatio(slidesDir)
 
       const project: Project = {
@@ -98,9 +153,7 @@ export function useProjectActions() {
         slides: slides.map(
           (s): Slide => ({
             ...s,
-            is_main: true,
-            enabled: true,
-            hotspots: [],
+            hotspots: s.hotspots ?? [],
             source_file: sourcePath,
           }),
         ),
@@ -273,21 +326,31 @@ export function useProjectActions() {
   }
 
   async function importAdditionalSlides() {
-    const project = useStore.getState().project
-    const projectDir = useStore.getState().projectDir
-    const projectPath = useStore.getState().projectPath
-    if (!project || !projectDir || !projectPath) return
-
     const sourcePath = await open({
       title: 'スライドを追加するファイルを選択',
       filters: [
-        {
-          name: 'Presentation (PDF / PPTX)',
-          extensions: ['pdf', 'pptx', 'ppt'],
-        },
+        { name: 'Presentation', extensions: PRESENTATION_EXTENSIONS },
+        { name: 'Images (ZIP)', extensions: ['zip'] },
       ],
     })
     if (!sourcePath) return
+    await appendSlides(sourcePath)
+  }
+
+  async function importImageFolder() {
+    const sourcePath = await open({
+      title: 'スライド画像のフォルダを選択',
+      directory: true,
+    })
+    if (!sourcePath) return
+    await appendSlides(sourcePath, true)
+  }
+
+  async function appendSlides(sourcePath: string, isFolder = false) {
+    const project = useStore.getState().project
+    const projectDir = useStore.getState().projectDir
+    const projectPath = useStore.getState().projectPath
+    if (!project || !projectDir || !projectPath) return
 
     const slidesDir = `${projectDir}/slides`
     const existingMaxIndex = project.slides.reduce(
@@ -296,20 +359,27 @@ export function useProjectActions() {
     )
     const startIndex = existingMaxIndex + 1
 
+    const pages = promptPageSelection()
+    if (pages === null) return
+
     setLoading(true, 'スライド画像を変換中...')
     try {
-      const newSlideInfos = await tauriCommands.convertToImagesWithOffset(
+      const newSlideInfos = await convertSource(
         sourcePath,
         slidesDir,
         startIndex,
+        {
+          render_dpi: project.render_dpi,
+          render_width: project.render_width,
+          pages,
+        },
+        isFolder,
       )
 
       const newSlides: Slide[] = newSlideInfos.map(
         (s): Slide => ({
           ...s,
-          is_main: true,
-          enabled: true,
-          hotspots: [],
+          hotspots: s.hotspots ?? [],
           source_file: sourcePath,
         }),
       )
@@ -356,6 +426,47 @@ export function useProjectActions() {
     }
   }
 
+  async function rerenderSlides() {
+    const project = useStore.getState().project
+    const projectDir = useStore.getState().projectDir
+    if (!project || !projectDir) return
+
+    const input = window.prompt(
+      'レンダリング解像度 (DPI) を入力してください。\n' +
+        '幅をピクセルで指定する場合は「1920px」のように入力します。',
+      project.render_width
+        ? `${project.render_width}px`
+        : String(project.render_dpi ?? 200),
+    )
+    if (!input) return
+
+    const trimmed = input.trim().toLowerCase()
+    const value = Number.parseFloat(trimmed)
+    if (!Number.isFinite(value) || value <= 0) {
+      alert(`無効な値です: ${input}`)
+      return
+    }
+    const isWidth = trimmed.endsWith('px')
+    const updated: Project = {
+      ...project,
+      render_dpi: isWidth ? undefined : value,
+      render_width: isWidth ? Math.round(value) : undefined,
+      updated_at: new Date().toISOString(),
+    }
+
+    setLoading(true, 'スライド画像を再レンダリング中...')
+    try {
+      const rerendered = await tauriCommands.rerenderProject(projectDir, updated)
+      setProject(rerendered)
+      useStore.getState().markDirty()
+      await loadAllImages(rerendered, projectDir)
+    } catch (err) {
+      alert(`再レンダリングに失敗しました: ${err}`)
+    } finally {
+      setLoading(false)
+    }
+  }
+
   function closeProject() {
     clearProject()
     clearImageCache()
@@ -370,6 +481,8 @@ export function useProjectActions() {
     exportHtml,
     exportPdf,
     importAdditionalSlides,
+    importImageFolder,
+    rerenderSlides,
     closeProject,
   }
 }
@@ -5,6 +5,8 @@ export type {
   Hotspot,
   HotspotStyle,
   TextOverlay,
+  TextLayer,
+  TextWord,
   NavigationStack,
   RecentProject,
 } from './project'
@@ -7,9 +7,13 @@ export interface Project {
   aspect_ratio: AspectRatio
   slides: Slide[]
   enable_analytics?: boolean
+  /** Render resolution; render_width (px) takes precedence over render_dpi */
+  render_dpi?: number
+  render_width?: number
 }
 
-export type AspectRatio = '16:9' | '4:3'
+/** "W:H", e.g. "16:9", "16:10", "32:9" or an exact pixel ratio */
+export type AspectRatio = string
 
 export interface Slide {
   id: string
@@ -22,6 +26,34 @@ export interface Slide {
   text_overlays?: TextOverlay[]
   graph_links?: string[]
   source_file?: string
+  /** Zero-based page of source_file this slide was rendered from */
+  source_page?: number
+  /** Rendered image size in pixels; decks may mix page sizes */
+  width?: number
+  height?: number
+  aspect_ratio?: string
+  /** Text extracted from the source page */
+  text_layer?: TextLayer
+  /** Speaker notes imported from the source deck */
+  notes?: string
+  /** Section of the source deck the slide belongs to */
+  section?: string
+}
+
+export interface TextLayer {
+  text: string
+  words: TextWord[]
+}
+
+/** A word on the page; position and size are percentages like hotspots */
+export interface TextWord {
+  text: string
+  x: number
+  y: number
+  w: number
+  h: number
+  /** Font size in points */
+  font_size: number
 }
 
 export interface HotspotStyle {